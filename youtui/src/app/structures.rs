@@ -100,7 +100,7 @@ pub struct ListSongAlbum {
 
 impl From<ParsedSongArtist> for ListSongArtist {
     fn from(value: ParsedSongArtist) -> Self {
-        let ParsedSongArtist { name, id } = value;
+        let ParsedSongArtist { name, id, .. } = value;
         Self {
             name,
             id: id.map(ArtistOrUploadArtistID::Artist),
@@ -0,0 +1,22 @@
+//! Feed arbitrary input through the home feed parsers, which should return an
+//! error for malformed input instead of panicking.
+//! The seed corpus in `corpus/home` links to the committed home fixtures.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ytmapi_rs::auth::BrowserToken;
+use ytmapi_rs::query::{GetContinuationsQuery, GetExploreQuery, GetHomeChipsQuery, GetHomeQuery};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(json) = std::str::from_utf8(data) else {
+        return;
+    };
+    let query = GetHomeQuery::new();
+    let _ = ytmapi_rs::process_json::<GetHomeQuery, BrowserToken>(json.to_string(), &query);
+    let _ = ytmapi_rs::process_json::<GetContinuationsQuery<GetHomeQuery>, BrowserToken>(
+        json.to_string(),
+        GetContinuationsQuery::new_mock_unchecked(&query),
+    );
+    let _ = ytmapi_rs::process_json::<_, BrowserToken>(json.to_string(), GetExploreQuery);
+    let _ = ytmapi_rs::process_json::<_, BrowserToken>(json.to_string(), GetHomeChipsQuery);
+});
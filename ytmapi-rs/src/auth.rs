@@ -4,7 +4,7 @@ use crate::client::{Client, QueryResponse};
 use crate::error::Result;
 use crate::parse::ProcessedResult;
 use crate::query::{GetQuery, PostQuery};
-use crate::utils::constants::{YTM_API_URL, YTM_PARAMS, YTM_PARAMS_KEY};
+use crate::utils::constants::{YTM_PARAMS, YTM_PARAMS_KEY};
 pub use browser::BrowserToken;
 use chrono::Utc;
 pub use oauth::{OAuthToken, OAuthTokenGenerator};
@@ -64,7 +64,7 @@ pub(crate) async fn raw_query_post<'a, A: AuthToken, Q: PostQuery>(
     tok: &A,
     c: &Client,
 ) -> Result<RawResult<'a, Q, A>> {
-    let url = format!("{YTM_API_URL}{}{YTM_PARAMS}{YTM_PARAMS_KEY}", q.path());
+    let url = format!("{}{}{YTM_PARAMS}{YTM_PARAMS_KEY}", c.api_url(), q.path());
     let mut body = json!({
         "context" : {
             "client" : {
@@ -75,16 +75,52 @@ pub(crate) async fn raw_query_post<'a, A: AuthToken, Q: PostQuery>(
         },
     });
     if let Some(body) = body.as_object_mut() {
-        body.append(&mut q.header());
+        merge_json_objects(body, q.header());
     } else {
         unreachable!("Body created in this function as an object")
     };
-    let QueryResponse { text, .. } = c
-        .post_json_query(url, tok.headers()?, &body, &q.params())
-        .await?;
+    let headers = merge_headers(tok.headers()?, q.extra_headers());
+    let QueryResponse { text, .. } = c.post_json_query(url, headers, &body, &q.params()).await?;
     Ok(RawResult::from_raw(text, q))
 }
 
+/// Append `extra` to `headers`, replacing any headers of the same name (case
+/// insensitive).
+pub(crate) fn merge_headers<'a>(
+    headers: impl IntoIterator<Item = (&'a str, Cow<'a, str>)>,
+    extra: Vec<(&'a str, Cow<'a, str>)>,
+) -> Vec<(&'a str, Cow<'a, str>)> {
+    let mut headers: Vec<_> = headers
+        .into_iter()
+        .filter(|(name, _)| {
+            !extra
+                .iter()
+                .any(|(extra, _)| extra.eq_ignore_ascii_case(name))
+        })
+        .collect();
+    headers.extend(extra);
+    headers
+}
+
+/// Merge `source` into `target`. Where both contain an object under the same
+/// key, the objects are merged instead of replaced - e.g so that a query can
+/// add fields to the request context.
+fn merge_json_objects(
+    target: &mut serde_json::Map<String, serde_json::Value>,
+    source: serde_json::Map<String, serde_json::Value>,
+) {
+    for (key, value) in source {
+        match (target.get_mut(&key), value) {
+            (Some(serde_json::Value::Object(target)), serde_json::Value::Object(source)) => {
+                merge_json_objects(target, source)
+            }
+            (_, value) => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
 pub(crate) async fn raw_query_get<'a, Q: GetQuery, A: AuthToken>(
     tok: &A,
     client: &Client,
@@ -56,6 +56,15 @@ impl AuthToken for BrowserToken {
 }
 
 impl BrowserToken {
+    /// Create a BrowserToken with dummy credentials - for testing purposes.
+    #[cfg(test)]
+    pub(crate) fn new_mock() -> Self {
+        Self {
+            sapisid: "mock_sapisid".to_string(),
+            client_version: "1.20250101.01.00".to_string(),
+            cookies: "SAPISID=mock_sapisid".to_string(),
+        }
+    }
     pub async fn from_str(cookie_str: &str, client: &Client) -> Result<Self> {
         let cookies = cookie_str.trim().to_string();
         let user_agent = USER_AGENT;
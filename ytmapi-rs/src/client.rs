@@ -1,4 +1,5 @@
 //! This module contains the basic HTTP client used in this library.
+use crate::utils::constants::YTM_API_URL;
 use crate::{Error, Result};
 use serde::Serialize;
 use std::borrow::Cow;
@@ -9,6 +10,7 @@ use std::borrow::Cow;
 #[derive(Debug, Clone)]
 pub struct Client {
     inner: reqwest::Client,
+    api_url: Cow<'static, str>,
 }
 /// Body that can be sent as a POST query using our client.
 pub enum Body {
@@ -54,30 +56,47 @@ impl QueryResponse {
 }
 
 impl Client {
+    fn from_inner(inner: reqwest::Client) -> Self {
+        Self {
+            inner,
+            api_url: YTM_API_URL.into(),
+        }
+    }
+    /// Send API queries to `url` instead of YouTube Music - for testing
+    /// purposes.
+    #[cfg(test)]
+    pub(crate) fn with_api_url(mut self, url: impl Into<String>) -> Self {
+        self.api_url = url.into().into();
+        self
+    }
+    /// The base url that API queries are sent to.
+    pub(crate) fn api_url(&self) -> &str {
+        &self.api_url
+    }
     /// Utilises reqwest's default tls choice for the enabled set of options.
     pub fn new() -> Result<Self> {
         let inner = reqwest::Client::builder().build()?;
-        Ok(Self { inner })
+        Ok(Self::from_inner(inner))
     }
     #[cfg(feature = "rustls-tls")]
     #[cfg_attr(docsrs, doc(cfg(feature = "rustls-tls")))]
     /// Force the use of rustls-tls
     pub fn new_rustls_tls() -> Result<Self> {
         let inner = reqwest::Client::builder().use_rustls_tls().build()?;
-        Ok(Self { inner })
+        Ok(Self::from_inner(inner))
     }
     #[cfg(feature = "native-tls")]
     #[cfg_attr(docsrs, doc(cfg(feature = "native-tls")))]
     /// Force the use of native-tls
     pub fn new_native_tls() -> Result<Self> {
         let inner = reqwest::Client::builder().use_native_tls().build()?;
-        Ok(Self { inner })
+        Ok(Self::from_inner(inner))
     }
     #[cfg(feature = "reqwest")]
     #[cfg_attr(docsrs, doc(cfg(feature = "reqwest")))]
     /// Re-use a pre-existing reqwest::Client.
     pub fn new_from_reqwest_client(client: reqwest::Client) -> Self {
-        Self { inner: client }
+        Self::from_inner(client)
     }
     /// Run a POST query, with url, body, key/kalue params and headers.
     pub async fn post_query<'a, I>(
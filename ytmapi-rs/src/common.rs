@@ -77,6 +77,43 @@ pub struct Thumbnail {
     pub url: String,
 }
 
+impl Thumbnail {
+    /// Get a url for this thumbnail that requests a particular resolution,
+    /// by rewriting the `=w{width}-h{height}` suffix. Other options in the
+    /// suffix, such as cropping, are kept.
+    /// Urls that do not support resizing (e.g video thumbnails from
+    /// i.ytimg.com) are returned unchanged.
+    pub fn with_size(&self, width: u32, height: u32) -> String {
+        let Some((host_and_path, last_segment)) = self.url.rsplit_once('/') else {
+            return self.url.clone();
+        };
+        if !["googleusercontent.com", "ggpht.com"]
+            .iter()
+            .any(|host| host_and_path.contains(host))
+        {
+            return self.url.clone();
+        }
+        let (id, options) = last_segment.split_once('=').unwrap_or((last_segment, ""));
+        let is_size_option = |option: &str| {
+            let mut chars = option.chars();
+            matches!(chars.next(), Some('w' | 'h' | 's'))
+                && !chars.as_str().is_empty()
+                && chars.all(|c| c.is_ascii_digit())
+        };
+        let options = [format!("w{width}"), format!("h{height}")]
+            .into_iter()
+            .chain(
+                options
+                    .split('-')
+                    .filter(|option| !option.is_empty() && !is_size_option(option))
+                    .map(ToString::to_string),
+            )
+            .collect::<Vec<_>>()
+            .join("-");
+        format!("{host_and_path}/{id}={options}")
+    }
+}
+
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 /// Set of both taste tokens.
 // Intentionally not marked non_exhaustive - not expecting this to change.
@@ -148,6 +185,17 @@ pub enum LikeStatus {
     Indifferent,
 }
 
+/// Current like status of an item and the params sent with each rating,
+/// taken from the like button in its menu.
+// Intentionally not marked non_exhaustive - not expecting this to change.
+#[derive(PartialEq, Clone, Debug, Deserialize, Serialize)]
+pub struct LikeFeedbackTokens {
+    pub status: LikeStatus,
+    pub like_params: Option<String>,
+    pub dislike_params: Option<String>,
+    pub indifferent_params: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Explicit {
     IsExplicit,
@@ -220,6 +268,17 @@ pub struct TasteTokenImpression<'a>(Cow<'a, str>);
 pub struct MoodCategoryParams<'a>(Cow<'a, str>);
 #[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
 pub struct SongTrackingUrl<'a>(Cow<'a, str>);
+/// Identifies a brand account, so that queries can be made on behalf of it.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Serialize, Deserialize)]
+pub struct BrandAccountID<'a>(Cow<'a, str>);
+
+impl ContinuationParams<'_> {
+    /// Returns true if the params are blank, in which case requesting them
+    /// would just return the same page again.
+    pub fn is_empty(&self) -> bool {
+        self.0.trim().is_empty()
+    }
+}
 
 impl_youtube_id!(UploadEntityID<'a>);
 impl_youtube_id!(SetVideoID<'a>);
@@ -246,3 +305,43 @@ impl_youtube_id!(MoodCategoryParams<'a>);
 impl_youtube_id!(SongTrackingUrl<'a>);
 impl_youtube_id!(UserVideosParams<'a>);
 impl_youtube_id!(UserPlaylistsParams<'a>);
+impl_youtube_id!(BrandAccountID<'a>);
+
+#[cfg(test)]
+mod tests {
+    use super::Thumbnail;
+
+    fn mock_thumbnail(url: &str) -> Thumbnail {
+        Thumbnail {
+            height: 60,
+            width: 60,
+            url: url.to_string(),
+        }
+    }
+    #[test]
+    fn test_thumbnail_with_size_existing_suffix() {
+        let thumbnail = mock_thumbnail("https://lh3.googleusercontent.com/abc123=w60-h60-l90-rj");
+        assert_eq!(
+            thumbnail.with_size(544, 544),
+            "https://lh3.googleusercontent.com/abc123=w544-h544-l90-rj"
+        );
+        let thumbnail = mock_thumbnail("https://yt3.ggpht.com/abc123=s88-c-k-c0x00ffffff-no-rj");
+        assert_eq!(
+            thumbnail.with_size(120, 120),
+            "https://yt3.ggpht.com/abc123=w120-h120-c-k-c0x00ffffff-no-rj"
+        );
+    }
+    #[test]
+    fn test_thumbnail_with_size_no_suffix() {
+        let thumbnail = mock_thumbnail("https://lh3.googleusercontent.com/abc123");
+        assert_eq!(
+            thumbnail.with_size(226, 226),
+            "https://lh3.googleusercontent.com/abc123=w226-h226"
+        );
+    }
+    #[test]
+    fn test_thumbnail_with_size_not_rewritable() {
+        let url = "https://i.ytimg.com/vi/u7K72X4eo_s/sddefault.jpg";
+        assert_eq!(mock_thumbnail(url).with_size(226, 226), url);
+    }
+}
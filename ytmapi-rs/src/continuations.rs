@@ -36,6 +36,47 @@ use crate::query::{GetContinuationsQuery, PostMethod, PostQuery, Query, QueryMet
 use crate::{ProcessedResult, Result};
 use futures::Stream;
 use std::fmt::Debug;
+use std::time::Duration;
+
+/// How to retry requests for continuations that fail due to a transport error,
+/// such as a dropped connection, when streaming.
+/// Each retry waits twice as long as the last, starting at `base_delay`.
+/// The default is not to retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RetryPolicy {
+    /// Maximum number of retries for each continuation request.
+    pub attempts: u32,
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            attempts,
+            base_delay,
+        }
+    }
+    fn delay(&self, retry: u32) -> Duration {
+        self.base_delay.saturating_mul(2u32.saturating_pow(retry))
+    }
+}
+
+/// Run `f`, retrying according to `policy` while it returns a retryable error.
+async fn retry_with_backoff<T, F: Future<Output = Result<T>>>(
+    policy: RetryPolicy,
+    mut f: impl FnMut() -> F,
+) -> Result<T> {
+    let mut retry = 0;
+    loop {
+        match f().await {
+            Err(e) if e.is_retryable() && retry < policy.attempts => {
+                tokio::time::sleep(policy.delay(retry)).await;
+                retry += 1;
+            }
+            res => return res,
+        }
+    }
+}
 
 /// This trait represents a result that can be streamed to get more results.
 /// It will contain continuation params, and a parsing function for its
@@ -68,10 +109,14 @@ where
 ///   Q: PostQuery - this simplifies code within this function.
 /// - a query can only be streamed if the output is Continuable - therefore we
 ///   specify Q::Output: ParseFromContinuable<Q>.
+///
+/// Continuation requests that fail due to a transport error are retried
+/// according to `retry`.
 pub(crate) fn stream<'a, Q, A>(
     query: &'a Q,
     client: &'a crate::client::Client,
     tok: &'a A,
+    retry: RetryPolicy,
 ) -> impl Stream<Item = Result<Q::Output>> + 'a
 where
     A: AuthToken,
@@ -99,9 +144,10 @@ where
                 }
             }
             if let Some(ref next_query) = maybe_next_query {
-                let next_res = PostMethod::call(next_query, client, tok)
-                    .await
-                    .and_then(|res| res.process());
+                let next_res =
+                    retry_with_backoff(retry, || PostMethod::call(next_query, client, tok))
+                        .await
+                        .and_then(|res| res.process());
                 let next_res =
                     next_res.and_then(|res| GetContinuationsQuery::from_continuation(res));
                 match next_res {
@@ -181,3 +227,57 @@ where
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use crate::auth::BrowserToken;
+    use crate::query::GetHomeQuery;
+    use crate::utils::serve_mock_responses;
+    use futures::{StreamExt, TryStreamExt};
+    use std::time::Duration;
+
+    async fn mock_api(responses: Vec<Option<String>>) -> crate::YtMusic<BrowserToken> {
+        let (addr, _) = serve_mock_responses(responses).await;
+        crate::YtMusic {
+            client: crate::Client::new()
+                .unwrap()
+                .with_api_url(format!("http://{addr}/youtubei/v1/")),
+            token: BrowserToken::new_mock(),
+        }
+    }
+    #[tokio::test]
+    async fn test_stream_retries_continuation() {
+        let first = tokio::fs::read_to_string("./test_json/get_home_20261014.json")
+            .await
+            .expect("Expect file read to pass during tests");
+        let continuation =
+            tokio::fs::read_to_string("./test_json/get_home_continuation_20261014.json")
+                .await
+                .expect("Expect file read to pass during tests");
+        // The first continuation request fails with a dropped connection.
+        let responses = vec![Some(first), None, Some(continuation)];
+        let query = GetHomeQuery::new();
+        let yt = mock_api(responses.clone()).await;
+        let retry = RetryPolicy::new(2, Duration::from_millis(1));
+        let pages: Vec<_> = yt
+            .stream_with_retry(&query, retry)
+            .take(2)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages[1].sections[0].title, "From the community");
+        // Without retries, the failure is returned and ends the stream.
+        let yt = mock_api(responses).await;
+        let pages: Vec<_> = yt.stream(&query).take(2).collect().await;
+        let err = pages[1].as_ref().unwrap_err();
+        assert!(err.is_retryable());
+    }
+    #[test]
+    fn test_retry_policy_backoff() {
+        let retry = RetryPolicy::new(3, Duration::from_millis(100));
+        assert_eq!(retry.delay(0), Duration::from_millis(100));
+        assert_eq!(retry.delay(2), Duration::from_millis(400));
+        assert_eq!(RetryPolicy::default().attempts, 0);
+    }
+}
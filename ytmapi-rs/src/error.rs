@@ -72,6 +72,23 @@ pub enum ErrorKind {
         message: String,
     },
     MissingUploadUrl,
+    /// InnerTube returned a sign in prompt instead of the expected contents.
+    /// The query requires authentication, or the credentials are no longer
+    /// valid.
+    AuthRequired,
+    /// InnerTube returned a response with no contents, which usually indicates
+    /// a broken or unauthenticated response.
+    EmptyResponse,
+    /// Tried to fetch the thumbnail of an item that has no thumbnails.
+    NoThumbnail,
+    /// Error parsing part of a response, with a description of the part that
+    /// was being parsed, e.g the kind of item.
+    /// [`Error::into_kind`] returns the kind of `source` rather than this, so
+    /// the context is only seen in the error message.
+    WithContext {
+        context: String,
+        source: Error,
+    },
 }
 /// The type we were attempting to pass from the Json.
 #[derive(Debug, Clone)]
@@ -81,8 +98,22 @@ pub enum ParseTarget {
 }
 impl Error {
     /// Extract the inner kind from the error for pattern matching.
+    /// Any context added whilst parsing is skipped, returning the kind of the
+    /// underlying error.
     pub fn into_kind(self) -> ErrorKind {
-        *self.inner
+        match *self.inner {
+            ErrorKind::WithContext { source, .. } => source.into_kind(),
+            kind => kind,
+        }
+    }
+    /// True if the error came from the HTTP client, e.g a dropped connection
+    /// or timeout, so that repeating the request may succeed.
+    /// Errors parsing the response are not retryable.
+    pub fn is_retryable(&self) -> bool {
+        match &*self.inner {
+            ErrorKind::WithContext { source, .. } => source.is_retryable(),
+            kind => matches!(kind, ErrorKind::Web { .. }),
+        }
     }
     pub(crate) fn invalid_user_agent<S: Into<String>>(user_agent: S) -> Self {
         Self {
@@ -149,6 +180,32 @@ impl Error {
             inner: Box::new(ErrorKind::MissingUploadUrl),
         }
     }
+    pub(crate) fn auth_required() -> Self {
+        Self {
+            inner: Box::new(ErrorKind::AuthRequired),
+        }
+    }
+    pub(crate) fn empty_response() -> Self {
+        Self {
+            inner: Box::new(ErrorKind::EmptyResponse),
+        }
+    }
+    #[cfg(feature = "thumbnail-fetch")]
+    pub(crate) fn no_thumbnail() -> Self {
+        Self {
+            inner: Box::new(ErrorKind::NoThumbnail),
+        }
+    }
+    /// Wrap the error with a description of what was being parsed when it
+    /// occurred.
+    pub(crate) fn with_context(self, context: impl Into<String>) -> Self {
+        Self {
+            inner: Box::new(ErrorKind::WithContext {
+                context: context.into(),
+                source: self,
+            }),
+        }
+    }
     pub(crate) fn web(message: impl Into<String>) -> Self {
         Self {
             inner: Box::new(ErrorKind::Web {
@@ -204,6 +261,12 @@ impl Display for ErrorKind {
             ErrorKind::MissingUploadUrl => {
                 write!(f, "expected an x-goog-upload-url but didn't get one")
             }
+            ErrorKind::AuthRequired => {
+                write!(f, "Query requires authentication - try signing in again")
+            }
+            ErrorKind::EmptyResponse => write!(f, "Response contained no contents"),
+            ErrorKind::NoThumbnail => write!(f, "No thumbnails available to fetch"),
+            ErrorKind::WithContext { context, source } => write!(f, "{context}: {source}"),
         }
     }
 }
@@ -90,15 +90,16 @@ use common::ApiOutcome;
 use continuations::ParseFromContinuable;
 #[doc(inline)]
 pub use error::{Error, Result};
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use json::Json;
-use parse::ParseFrom;
 #[doc(inline)]
 pub use parse::ProcessedResult;
-use query::{PostQuery, Query, QueryMethod};
+use parse::{HomeSections, ParseFrom};
+use query::{GetHomeQuery, PostQuery, Query, QueryMethod};
 use std::borrow::Borrow;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::Path;
+use tokio_util::sync::CancellationToken;
 
 #[macro_use]
 mod utils;
@@ -311,7 +312,91 @@ impl<A: AuthToken> YtMusic<A> {
         Q: PostQuery,
         Q::Output: ParseFromContinuable<Q>,
     {
-        continuations::stream(query, &self.client, &self.token)
+        continuations::stream(query, &self.client, &self.token, Default::default())
+    }
+    /// Stream a query that has 'continuations', retrying continuation requests
+    /// that fail due to a transport error according to `retry`.
+    /// Useful when paging through many continuations, where a single dropped
+    /// connection would otherwise end the stream.
+    /// # Usage
+    /// ```no_run
+    /// use futures::stream::TryStreamExt;
+    /// use std::time::Duration;
+    /// use ytmapi_rs::continuations::RetryPolicy;
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("").await?;
+    /// let query = ytmapi_rs::query::GetHomeQuery::new();
+    /// let retry = RetryPolicy::new(3, Duration::from_millis(500));
+    /// let results = yt
+    ///     .stream_with_retry(&query, retry)
+    ///     .try_collect::<Vec<_>>()
+    ///     .await?;
+    /// # Ok::<(), ytmapi_rs::Error>(())
+    /// # };
+    /// ```
+    pub fn stream_with_retry<'a, Q>(
+        &'a self,
+        query: &'a Q,
+        retry: continuations::RetryPolicy,
+    ) -> impl Stream<Item = Result<Q::Output>> + 'a
+    where
+        Q: Query<A>,
+        Q: PostQuery,
+        Q::Output: ParseFromContinuable<Q>,
+    {
+        continuations::stream(query, &self.client, &self.token, retry)
+    }
+    /// Stream the home feed, yielding the whole feed loaded so far after each
+    /// page, instead of each page individually. Chips from the first page are
+    /// preserved.
+    /// Useful for UIs that re-render the whole feed as pages load.
+    /// # Usage
+    /// ```no_run
+    /// use futures::stream::TryStreamExt;
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("").await?;
+    /// let query = ytmapi_rs::query::GetHomeQuery::new();
+    /// let mut snapshots = std::pin::pin!(yt.home_snapshots(&query));
+    /// while let Some(feed) = snapshots.try_next().await? {
+    ///     println!("Loaded {} sections", feed.sections.len());
+    /// }
+    /// # Ok::<(), ytmapi_rs::Error>(())
+    /// # };
+    /// ```
+    pub fn home_snapshots<'a>(
+        &'a self,
+        query: &'a GetHomeQuery<'_>,
+    ) -> impl Stream<Item = Result<HomeSections>> + 'a {
+        parse::home_snapshots(self.stream(query))
+    }
+    /// As for [`YtMusic::home_snapshots`], but the stream ends as soon as
+    /// `cancel` is cancelled, e.g when a UI closes the screen displaying the
+    /// feed. A request that is in flight when `cancel` is cancelled is dropped
+    /// instead of awaited.
+    /// Note that dropping the stream also stops any further requests.
+    /// # Usage
+    /// ```no_run
+    /// use futures::stream::TryStreamExt;
+    /// use tokio_util::sync::CancellationToken;
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("").await?;
+    /// let query = ytmapi_rs::query::GetHomeQuery::new();
+    /// let cancel = CancellationToken::new();
+    /// let mut snapshots = std::pin::pin!(yt.home_snapshots_with_cancel(&query, cancel.clone()));
+    /// while let Some(feed) = snapshots.try_next().await? {
+    ///     if feed.sections.len() >= 20 {
+    ///         cancel.cancel();
+    ///     }
+    /// }
+    /// # Ok::<(), ytmapi_rs::Error>(())
+    /// # };
+    /// ```
+    pub fn home_snapshots_with_cancel<'a>(
+        &'a self,
+        query: &'a GetHomeQuery<'_>,
+        cancel: CancellationToken,
+    ) -> impl Stream<Item = Result<HomeSections>> + 'a {
+        parse::home_snapshots(self.stream(query).take_until(cancel.cancelled_owned()))
     }
     /// Return the source JSON from streaming a query that has 'continuations',
     /// i.e can continue to stream results.
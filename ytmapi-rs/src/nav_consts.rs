@@ -14,10 +14,15 @@ pub const MUSIC_CARD_SHELF: &str = "/musicCardShelfRenderer";
 pub const MUSIC_PLAYLIST_SHELF: &str = "/musicPlaylistShelfRenderer";
 pub const GRID: &str = "/gridRenderer";
 pub const MENU: &str = "/menu/menuRenderer";
+pub const MENU_TOP_LEVEL_BUTTONS: &str = concatcp!(MENU, "/topLevelButtons");
 pub const MENU_SERVICE: &str = "/menuServiceItemRenderer/serviceEndpoint";
 pub const _TOGGLE_MENU: &str = "/toggleMenuServiceItemRenderer";
 pub const PLAY_BUTTON: &str =
     "/overlay/musicItemThumbnailOverlayRenderer/content/musicPlayButtonRenderer";
+pub const THUMBNAIL_OVERLAY_PLAY: &str = concatcp!(
+    "/thumbnailOverlay/musicItemThumbnailOverlayRenderer/content/musicPlayButtonRenderer",
+    "/playNavigationEndpoint"
+);
 pub const NAVIGATION_BROWSE: &str = "/navigationEndpoint/browseEndpoint";
 pub const PAGE_TYPE: &str =
     "/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType";
@@ -39,17 +44,19 @@ pub const BADGE_PATH: &str =
     "/0/musicInlineBadgeRenderer/accessibilityData/accessibilityData/label";
 pub const LIVE_BADGE_PATH: &str = "/0/liveBadgeRenderer/accessibility/accessibilityData/label";
 pub const CATEGORY_PARAMS: &str = "/clickCommand/browseEndpoint/params";
+pub const CHIP_CLOUD_CHIPS: &str = "/header/chipCloudRenderer/chips";
 pub const MRLIR: &str = "/musicResponsiveListItemRenderer";
 pub const MMRLIR: &str = "/musicMultiRowListItemRenderer";
 pub const PPR: &str = "/playlistPanelRenderer";
 pub const _MRLIFCR: &str = "/musicResponsiveListItemFlexColumnRenderer";
 pub const MTRIR: &str = "/musicTwoRowItemRenderer";
+pub const MTCIR: &str = "/musicTwoColumnItemRenderer";
 pub const TASTE_PROFILE_ITEMS: &str = "/contents/tastebuilderRenderer/contents";
 pub const TASTE_ITEM_CONTENTS: &str = "/tastebuilderItemListRenderer/contents";
 pub const TASTE_PROFILE_SELECTION: &str = "/tastebuilderItemRenderer/selectionFormValue";
 pub const TASTE_PROFILE_IMPRESSION: &str = "/tastebuilderItemRenderer/impressionFormValue";
 pub const TASTE_PROFILE_ARTIST: &str = "/tastebuilderItemRenderer/title/runs/0/text";
-pub const _SECTION_LIST_CONTINUATION: &str = "/continuationContents/sectionListContinuation";
+pub const SECTION_LIST_CONTINUATION: &str = "/continuationContents/sectionListContinuation";
 pub const PLAYLIST_PANEL_CONTINUATION: &str = "/continuationContents/playlistPanelContinuation";
 pub const CONTINUATION_RENDERER_COMMAND: &str =
     "/continuationItemRenderer/continuationEndpoint/continuationCommand/token";
@@ -66,7 +73,7 @@ pub const DESCRIPTION_SHELF: &str = "/musicDescriptionShelfRenderer";
 pub const DESCRIPTION_SHELF_RUNS: &str =
     "/description/musicDescriptionShelfRenderer/description/runs";
 pub const CAROUSEL: &str = "/musicCarouselShelfRenderer";
-pub const _IMMERSIVE_CAROUSEL: &str = "/musicImmersiveCarouselShelfRenderer";
+pub const IMMERSIVE_CAROUSEL: &str = "/musicImmersiveCarouselShelfRenderer";
 pub const _FRAMEWORK_MUTATIONS: &str = "/frameworkUpdates/entityBatchUpdate/mutations";
 pub const INDEX_TEXT: &str = "/index/runs/0/text";
 pub const RESPONSIVE_HEADER: &str = "/musicResponsiveHeaderRenderer";
@@ -108,6 +115,8 @@ pub const FOREGROUND_THUMBNAIL_RENDERER: &str =
     concatcp!("/foregroundThumbnail/musicThumbnailRenderer", THUMBNAIL);
 pub const THUMBNAIL_RENDERER: &str =
     concatcp!("/thumbnailRenderer/musicThumbnailRenderer", THUMBNAIL);
+pub const THUMBNAIL_RENDERER_MONTAGE: &str =
+    "/thumbnailRenderer/musicMultiThumbnailRenderer/thumbnails";
 pub const THUMBNAIL_CROPPED: &str =
     concatcp!("/thumbnail/croppedSquareThumbnailRenderer", THUMBNAIL);
 pub const STRAPLINE_THUMBNAIL: &str =
@@ -125,7 +134,10 @@ pub const MENU_PLAYLIST_ID: &str = concatcp!(
 );
 pub const DESCRIPTION: &str = concatcp!("/description", RUN_TEXT);
 pub const _CAROUSEL_CONTENTS: &str = concatcp!(CAROUSEL, "/contents");
-pub const CAROUSEL_TITLE: &str = concatcp!("/header/musicCarouselShelfBasicHeaderRenderer", TITLE);
+pub const CAROUSEL_HEADER: &str = "/header/musicCarouselShelfBasicHeaderRenderer";
+pub const IMMERSIVE_CAROUSEL_HEADER: &str =
+    "/header/musicImmersiveCarouselShelfBasicHeaderRenderer";
+pub const CAROUSEL_TITLE: &str = concatcp!(CAROUSEL_HEADER, TITLE);
 pub const _CARD_SHELF_TITLE: &str =
     concatcp!("/header/musicCardShelfHeaderBasicRenderer", TITLE_TEXT);
 #[cfg_attr(
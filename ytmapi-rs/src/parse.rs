@@ -21,7 +21,7 @@
 //! `json-crawler` as a dependency and use the provided
 //! `From<ProcessedResult> for JsonCrawlerOwned` implementation.
 use crate::auth::AuthToken;
-use crate::common::{AlbumID, ArtistChannelID, Thumbnail};
+use crate::common::{AlbumID, ArtistChannelID, Thumbnail, YoutubeID};
 use crate::json::Json;
 use crate::nav_consts::*;
 use crate::{RawResult, Result, error};
@@ -36,6 +36,8 @@ mod artist;
 pub use artist::*;
 mod history;
 pub use history::*;
+mod home;
+pub use home::*;
 mod library;
 pub use library::*;
 mod playlist;
@@ -78,11 +80,94 @@ pub enum EpisodeDuration {
     Recorded { duration: String },
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 // Intentionally not marked non_exhaustive - not expecting this to change.
 pub struct ParsedSongArtist {
     pub name: String,
     pub id: Option<ArtistChannelID<'static>>,
+    /// Page type of the link to the artist, e.g "MUSIC_PAGE_TYPE_ARTIST", if
+    /// the parser reads it. See [`ParsedSongArtist::channel_kind`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_type: Option<String>,
+}
+// Only parsers that read the page type set it, so it's left out of the output
+// of the others.
+impl Debug for ParsedSongArtist {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut s = f.debug_struct("ParsedSongArtist");
+        s.field("name", &self.name).field("id", &self.id);
+        if let Some(page_type) = &self.page_type {
+            s.field("page_type", page_type);
+        }
+        s.finish()
+    }
+}
+impl ParsedSongArtist {
+    /// Infer the kind of channel the artist links to.
+    /// Official artist, topic and user channels all share the same `UC` id
+    /// format, so are told apart by the page type of the link. Topic channels
+    /// are artist pages whose name ends in " - Topic".
+    pub fn channel_kind(&self) -> ChannelKind {
+        let Some(id) = &self.id else {
+            return ChannelKind::Missing;
+        };
+        let id = id.get_raw();
+        if id.starts_with("UC") {
+            match self.page_type.as_deref() {
+                Some("MUSIC_PAGE_TYPE_ARTIST") if self.name.ends_with(" - Topic") => {
+                    ChannelKind::Topic
+                }
+                Some("MUSIC_PAGE_TYPE_ARTIST") => ChannelKind::Artist,
+                Some("MUSIC_PAGE_TYPE_USER_CHANNEL") => ChannelKind::UserChannel,
+                _ => ChannelKind::Channel,
+            }
+        } else if id.starts_with("MPLA") {
+            ChannelKind::LibraryArtist
+        } else if id.starts_with("FEmusic_library_privately_owned_artist") {
+            ChannelKind::UploadArtist
+        } else {
+            ChannelKind::Unknown
+        }
+    }
+}
+/// Join the names of `artists` for display, separating all but the last pair
+/// with commas and the last pair with `conjunction`, e.g "A, B & C" when
+/// `conjunction` is "&".
+pub fn artists_display(artists: &[ParsedSongArtist], conjunction: &str) -> String {
+    match artists {
+        [] => String::new(),
+        [only] => only.name.clone(),
+        [rest @ .., last] => {
+            let rest = rest
+                .iter()
+                .map(|artist| artist.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{rest} {conjunction} {}", last.name)
+        }
+    }
+}
+/// The kind of channel a [`ParsedSongArtist`] links to, as returned by
+/// [`ParsedSongArtist::channel_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ChannelKind {
+    /// An official artist channel.
+    Artist,
+    /// An auto-generated topic channel for an artist.
+    Topic,
+    /// A regular user's channel.
+    UserChannel,
+    /// A `UC` channel whose kind wasn't included in the response.
+    Channel,
+    /// An artist from the user's library, with an `MPLA` prefixed id.
+    LibraryArtist,
+    /// An artist of the user's uploaded songs.
+    UploadArtist,
+    /// An id that isn't in a recognised format.
+    Unknown,
+    /// The artist isn't linked to a channel, e.g a plain text credit.
+    Missing,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 // Intentionally not marked non_exhaustive - not expecting this to change.
@@ -179,6 +264,7 @@ fn parse_song_artist(data: &mut impl JsonCrawler) -> Result<ParsedSongArtist> {
     Ok(ParsedSongArtist {
         name: data.take_value_pointer("/text")?,
         id: data.take_value_pointer(NAVIGATION_BROWSE_ID).ok(),
+        page_type: None,
     })
 }
 
@@ -212,3 +298,101 @@ fn parse_fixed_column_item<T: DeserializeOwned>(
     let pointer = format!("{}/text/runs/0/text", fixed_column_item_pointer(col_idx));
     Ok(item.take_value_pointer(pointer)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ChannelKind, ParsedSongArtist, artists_display};
+    use crate::common::{ArtistChannelID, YoutubeID};
+
+    fn artist(id: Option<&'static str>) -> ParsedSongArtist {
+        ParsedSongArtist {
+            name: "Portishead".to_string(),
+            id: id.map(ArtistChannelID::from_raw),
+            page_type: None,
+        }
+    }
+    #[test]
+    fn test_artists_display() {
+        let artists = [
+            artist(None),
+            ParsedSongArtist {
+                name: "Tricky".to_string(),
+                id: None,
+                page_type: None,
+            },
+            ParsedSongArtist {
+                name: "Massive Attack".to_string(),
+                id: None,
+                page_type: None,
+            },
+        ];
+        assert_eq!(artists_display(&artists[..0], "&"), "");
+        assert_eq!(artists_display(&artists[..1], "&"), "Portishead");
+        assert_eq!(artists_display(&artists[..2], "&"), "Portishead & Tricky");
+        assert_eq!(
+            artists_display(&artists, "&"),
+            "Portishead, Tricky & Massive Attack"
+        );
+        assert_eq!(
+            artists_display(&artists, "y"),
+            "Portishead, Tricky y Massive Attack"
+        );
+    }
+    #[test]
+    fn test_parsed_song_artist_channel_kind() {
+        let channel = |name: &str, id, page_type: &str| ParsedSongArtist {
+            name: name.to_string(),
+            id: Some(ArtistChannelID::from_raw(id)),
+            page_type: Some(page_type.to_string()),
+        };
+        // Artist, topic and user channels all use the same id format.
+        assert_eq!(
+            channel(
+                "Portishead",
+                "UCJp-y9HzDEmPpDJolDT9ynQ",
+                "MUSIC_PAGE_TYPE_ARTIST"
+            )
+            .channel_kind(),
+            ChannelKind::Artist
+        );
+        assert_eq!(
+            channel(
+                "Portishead - Topic",
+                "UCvjwQ8M2pGQLoY2bKd5qTDg",
+                "MUSIC_PAGE_TYPE_ARTIST"
+            )
+            .channel_kind(),
+            ChannelKind::Topic
+        );
+        assert_eq!(
+            channel(
+                "Mock Curator",
+                "UCTBgoRAoQd7qgqWNlMxCnGw",
+                "MUSIC_PAGE_TYPE_USER_CHANNEL"
+            )
+            .channel_kind(),
+            ChannelKind::UserChannel
+        );
+        // Without a page type the kind of channel is unknown.
+        assert_eq!(
+            artist(Some("UCJp-y9HzDEmPpDJolDT9ynQ")).channel_kind(),
+            ChannelKind::Channel
+        );
+        assert_eq!(
+            artist(Some("MPLAUCJp-y9HzDEmPpDJolDT9ynQ")).channel_kind(),
+            ChannelKind::LibraryArtist
+        );
+        assert_eq!(
+            artist(Some(
+                "FEmusic_library_privately_owned_artist_detaila_po_CLPk_bWp8eD5bhIMcG9ydGlzaGVhZA"
+            ))
+            .channel_kind(),
+            ChannelKind::UploadArtist
+        );
+        assert_eq!(
+            artist(Some("VLPLmock")).channel_kind(),
+            ChannelKind::Unknown
+        );
+        assert_eq!(artist(None).channel_kind(), ChannelKind::Missing);
+    }
+}
@@ -0,0 +1,2565 @@
+use super::{
+    ChannelKind, ParseFrom, ParsedSongAlbum, ParsedSongArtist, ProcessedResult,
+    fixed_column_item_pointer, flex_column_item_pointer, parse_flex_column_item,
+    parse_library_management_items_from_menu, parse_song_artist,
+};
+use crate::common::{
+    AlbumID, AlbumType, ArtistChannelID, ContinuationParams, Explicit, LibraryManager,
+    LikeFeedbackTokens, LikeStatus, MoodCategoryParams, PlaylistID, PodcastID, Thumbnail, VideoID,
+    YoutubeID,
+};
+use crate::continuations::ParseFromContinuable;
+use crate::nav_consts::{
+    CAROUSEL, CAROUSEL_HEADER, CHIP_CLOUD_CHIPS, CONTINUATION_PARAMS, IMMERSIVE_CAROUSEL,
+    IMMERSIVE_CAROUSEL_HEADER, MENU_ITEMS, MENU_TOP_LEVEL_BUTTONS, MRLIR, MTCIR, MTRIR,
+    NAVIGATION_BROWSE, NAVIGATION_BROWSE_ID, NAVIGATION_VIDEO_ID, NAVIGATION_VIDEO_TYPE,
+    NAVIGATION_WATCH_PLAYLIST_ID, PAGE_TYPE, PLAY_BUTTON, SECTION_LIST_CONTINUATION,
+    SINGLE_COLUMN_TAB, SUBTITLE, SUBTITLE_RUNS, TEXT_RUN_TEXT, THUMBNAIL, THUMBNAIL_OVERLAY_PLAY,
+    THUMBNAIL_RENDERER, THUMBNAIL_RENDERER_MONTAGE, THUMBNAILS, TITLE, TITLE_TEXT, WATCH_VIDEO_ID,
+};
+use crate::query::{
+    GetAlbumQuery, GetArtistQuery, GetContinuationsQuery, GetExploreQuery, GetHomeChipsQuery,
+    GetHomeQuery, GetPlaylistDetailsQuery,
+};
+use crate::youtube_enums::YoutubeMusicVideoType;
+use crate::{Error, Result};
+use const_format::concatcp;
+use futures::{Stream, StreamExt};
+use json_crawler::{CrawlerResult, JsonCrawler, JsonCrawlerBorrowed, JsonCrawlerOwned};
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "test-util")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+mod builder;
+mod flat;
+#[cfg(feature = "serde_json")]
+mod ytmusicapi;
+#[cfg(feature = "test-util")]
+pub use builder::*;
+pub use flat::*;
+#[cfg(test)]
+mod tests;
+
+/// Built-in view and subscriber tokens, by language code.
+const METRIC_TOKENS: [(&str, &str, &str); 6] = [
+    ("en", "views", "subscribers"),
+    ("es", "visualizaciones", "suscriptores"),
+    ("fr", "vues", "abonnés"),
+    ("de", "Aufrufe", "Abonnenten"),
+    ("pt", "visualizações", "inscritos"),
+    ("ja", "回視聴", "チャンネル登録者"),
+];
+// TODO: i18n - these are the only locales tested so far.
+const LIBRARY_TOKENS: [&str; 2] = ["from your library", "de tu biblioteca"];
+const LISTEN_AGAIN_TOKENS: [&str; 2] = ["listen again", "vuelve a escuchar"];
+const QUICK_PICKS_TOKENS: [&str; 2] = ["quick picks", "selección rápida"];
+const MIX_TOKENS: [&str; 1] = ["mix"];
+const TRACK_COUNT_TOKENS: [&str; 4] = ["song", "track", "canción", "canciones"];
+/// Abbreviations used for large numbers, e.g '1.2M', '3,4 Mio.' or '120万'.
+const NUMBER_SUFFIXES: [(&str, f64); 15] = [
+    ("K", 1e3),
+    ("k", 1e3),
+    ("M", 1e6),
+    ("B", 1e9),
+    ("mil", 1e3),
+    ("mi", 1e6),
+    ("Tsd.", 1e3),
+    ("Mio.", 1e6),
+    ("Mrd.", 1e9),
+    ("Md", 1e9),
+    ("L", 1e5),
+    ("lakh", 1e5),
+    ("Cr", 1e7),
+    ("万", 1e4),
+    ("億", 1e8),
+];
+/// Mixes have radio playlist ids, except curated playlists which share the
+/// prefix but can be browsed like any other playlist.
+const MIX_PLAYLIST_PREFIX: &str = "RD";
+const CURATED_PLAYLIST_PREFIX: &str = "RDCLAK";
+/// Browse id of the full list of moods and genres, linked to by the trailing
+/// chip of the chip cloud.
+const MOODS_AND_GENRES_BROWSE_ID: &str = "FEmusic_moods_and_genres";
+const ANIMATED_THUMBNAIL_URL: &str =
+    "/thumbnail/musicAnimatedThumbnailRenderer/animatedThumbnail/thumbnails/0/url";
+const SUBSCRIBE_BUTTON_ENDPOINTS: &str =
+    "/subscribeButton/subscribeButtonRenderer/serviceEndpoints";
+#[cfg(feature = "thumbnail-fetch")]
+const THUMBNAIL_FETCH_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+#[derive(PartialEq, Debug, Clone, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+/// The YouTube Music home feed.
+pub struct HomeSections {
+    /// Mood chips that can be used to filter the feed, using
+    /// [`GetHomeQuery::with_mood`].
+    pub chips: Vec<HomeMoodChip>,
+    pub sections: Vec<HomeSection>,
+    /// The mood chip used to filter the feed, if it was fetched using
+    /// [`GetHomeQuery::with_mood`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_chip: Option<HomeMoodChip>,
+    /// Params of the trailing 'More' chip, which links to the full list of
+    /// moods and genres instead of filtering the feed. The chip is not
+    /// included in `chips`. If the chip has no params, use
+    /// [`crate::query::GetMoodCategoriesQuery`] to get the full list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub more_moods_params: Option<MoodCategoryParams<'static>>,
+    /// Rows or items that failed to parse and were skipped.
+    // Defaulted, so that home feeds serialized before this was added can still
+    // be deserialized.
+    #[serde(default)]
+    pub warnings: Vec<ParseWarning>,
+    /// Names of the unsupported shelf and item renderers that were skipped,
+    /// in the order first seen. Only populated if
+    /// [`HomeParseOptions::audit_renderers`] is set.
+    #[serde(default)]
+    pub unknown_renderers: Vec<String>,
+}
+
+/// The localised words used to identify view and subscriber counts in card
+/// subtitles, e.g 'views' in '1.2M views'.
+/// The default contains the words for all built-in locales.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct MetricLexicon {
+    views: Vec<String>,
+    subscribers: Vec<String>,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+/// A non-fatal error encountered whilst parsing, where the offending row or
+/// item was skipped instead of failing the whole query.
+pub struct ParseWarning {
+    pub message: String,
+}
+
+/// A [`HomeSections`] along with the time it was fetched, so that cached feeds
+/// can be expired.
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct CachedHomeSections {
+    pub fetched_at: std::time::SystemTime,
+    pub data: HomeSections,
+}
+
+/// Summary counts for a [`HomeSections`], returned by
+/// [`HomeSections::stats`].
+#[derive(PartialEq, Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct HomeStats {
+    pub sections: usize,
+    pub chips: usize,
+    pub total_items: usize,
+    /// Number of items of each kind. Kinds with no items are not included.
+    pub items_by_kind: HashMap<HomeContentKind, usize>,
+    /// Number of explicit songs, videos and albums.
+    pub explicit_items: usize,
+    /// Number of songs, videos and albums that are not explicit, including
+    /// those whose explicit status is unknown.
+    pub clean_items: usize,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct HomeMoodChip {
+    pub title: String,
+    pub params: MoodCategoryParams<'static>,
+    /// True if this is the mood currently used to filter the feed.
+    #[serde(default)]
+    pub selected: bool,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+/// A single shelf of the home feed, e.g 'Listen again'.
+pub struct HomeSection {
+    pub title: String,
+    /// Smaller text displayed above the title, e.g the user's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strapline: Option<String>,
+    /// Header thumbnail in each available size. Use
+    /// [`HomeSection::thumbnail`] to get the first.
+    #[serde(default)]
+    pub thumbnails: Vec<Thumbnail>,
+    /// URL of an animated preview of the shelf, e.g a GIF or WebM, displayed
+    /// in place of the header thumbnail by some featured shelves.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub animated_thumbnail: Option<String>,
+    /// Params for the shelf's 'More' button, if it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub more_params: Option<MoodCategoryParams<'static>>,
+    /// Localised label of the shelf's 'More' button, e.g "View all 24".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub more_label: Option<String>,
+    /// Params for the shelf's 'Shuffle' button, if it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shuffle_params: Option<MoodCategoryParams<'static>>,
+    /// Opaque params sent by the app when logging impressions of the shelf.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_params: Option<String>,
+    /// Index of the section within the feed, counting from the top of the
+    /// first page. Only sections that were parsed are counted.
+    #[serde(default)]
+    pub position: usize,
+    pub contents: Vec<HomeContent>,
+}
+
+/// The kind of a home section, as determined by
+/// [`HomeSection::section_kind`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum HomeSectionKind {
+    /// Items the user has played recently.
+    ListenAgain,
+    QuickPicks,
+    Recommended,
+    /// Shelf consisting mostly of mixes and radios.
+    MoodMix,
+    Other,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum HomeContent {
+    Song(HomeSong),
+    Video(HomeVideo),
+    Album(HomeAlbum),
+    Playlist(HomePlaylist),
+    Artist(HomeArtist),
+    /// A mix or radio, that can be played directly.
+    WatchPlaylist(HomeWatchPlaylist),
+    /// A podcast show.
+    Podcast(HomePodcast),
+}
+
+/// The kind of a [`HomeContent`], without its contents.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HomeContentKind {
+    Song,
+    Video,
+    Album,
+    Playlist,
+    Artist,
+    WatchPlaylist,
+    Podcast,
+}
+
+/// The shape of an item's thumbnail, as returned by
+/// [`HomeContent::thumbnail_aspect`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum ThumbnailAspect {
+    /// Songs, albums, playlists, artists and podcasts.
+    Square,
+    /// 16:9, as used by videos.
+    Wide,
+}
+
+/// A single row of the feed as a linear list, as returned by
+/// [`HomeSections::as_rows`].
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum HomeRow<'a> {
+    /// The header at the start of each section.
+    Header {
+        title: &'a str,
+        strapline: Option<&'a str>,
+    },
+    Item(&'a HomeContent),
+}
+
+/// Identifies a [`HomeContent`] by its kind and id, for use as a cache key.
+/// Returned by [`HomeContent::cache_key`].
+#[derive(PartialEq, Eq, Debug, Clone, Hash)]
+pub struct HomeContentKey {
+    pub kind: HomeContentKind,
+    pub id: String,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct HomeSong {
+    pub title: String,
+    pub video_id: VideoID<'static>,
+    pub artists: Vec<ParsedSongArtist>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub album: Option<ParsedSongAlbum>,
+    /// None if the card doesn't show explicit status. See
+    /// [`HomeSong::is_explicit`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explicit: Option<Explicit>,
+    /// True if the item is only available to YouTube Music Premium
+    /// subscribers.
+    #[serde(default)]
+    pub is_premium_only: bool,
+    /// Duration as displayed, e.g "3:45", if shown on the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_seconds: Option<u32>,
+    /// Tokens to add or remove the song from the library, if the card has a
+    /// menu and the user is signed in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub library_management: Option<LibraryManager>,
+    /// Like status and params to rate the song, if the card's menu has a like
+    /// button.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub like_feedback_tokens: Option<LikeFeedbackTokens>,
+    pub thumbnails: Vec<Thumbnail>,
+    /// The full subtitle as displayed on the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// True if the item is in a shelf of items from the user's library.
+    #[serde(default)]
+    pub from_library: bool,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct HomeVideo {
+    pub title: String,
+    pub video_id: VideoID<'static>,
+    pub artists: Vec<ParsedSongArtist>,
+    /// None if the card doesn't show explicit status. See
+    /// [`HomeVideo::is_explicit`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explicit: Option<Explicit>,
+    /// True if the item is only available to YouTube Music Premium
+    /// subscribers.
+    #[serde(default)]
+    pub is_premium_only: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub views: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+    /// The full subtitle as displayed on the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// True if the item is in a shelf of items from the user's library.
+    #[serde(default)]
+    pub from_library: bool,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct HomeAlbum {
+    pub title: String,
+    pub album_id: AlbumID<'static>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub album_type: Option<AlbumType>,
+    pub artists: Vec<ParsedSongArtist>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<String>,
+    /// Number of tracks, if shown on the card, e.g from "12 songs".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_count: Option<u32>,
+    /// None if the card doesn't show explicit status. See
+    /// [`HomeAlbum::is_explicit`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explicit: Option<Explicit>,
+    /// True if the item is only available to YouTube Music Premium
+    /// subscribers.
+    #[serde(default)]
+    pub is_premium_only: bool,
+    pub thumbnails: Vec<Thumbnail>,
+    /// The full subtitle as displayed on the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// Params for the card's play button, to start playback directly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_params: Option<String>,
+    /// The video the card's play button starts playback from, if it names
+    /// one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_video_id: Option<VideoID<'static>>,
+    /// True if the item is in a shelf of items from the user's library.
+    #[serde(default)]
+    pub from_library: bool,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct HomePlaylist {
+    pub title: String,
+    pub playlist_id: PlaylistID<'static>,
+    /// The browse id as returned by the server, i.e `playlist_id` prefixed
+    /// with 'VL'.
+    #[serde(default)]
+    pub browse_id: String,
+    /// Channels that authored the playlist. Empty if the author isn't linked,
+    /// e.g playlists curated by YouTube Music.
+    pub author: Vec<ParsedSongArtist>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_kind: Option<HomeAuthorKind>,
+    pub thumbnails: Vec<Thumbnail>,
+    /// The full subtitle as displayed on the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// Params for the card's play button, to start playback directly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_params: Option<String>,
+    /// The video the card's play button starts playback from, if it names
+    /// one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub play_video_id: Option<VideoID<'static>>,
+    /// True if the item is in a shelf of items from the user's library.
+    #[serde(default)]
+    pub from_library: bool,
+}
+
+/// Callbacks for each kind of [`HomeContent`], dispatched using
+/// [`HomeContent::accept`].
+/// All methods do nothing by default, so only the kinds of interest need to be
+/// implemented.
+pub trait HomeContentVisitor {
+    fn visit_song(&mut self, _song: &HomeSong) {}
+    fn visit_video(&mut self, _video: &HomeVideo) {}
+    fn visit_album(&mut self, _album: &HomeAlbum) {}
+    fn visit_playlist(&mut self, _playlist: &HomePlaylist) {}
+    fn visit_artist(&mut self, _artist: &HomeArtist) {}
+    fn visit_watch_playlist(&mut self, _watch_playlist: &HomeWatchPlaylist) {}
+    fn visit_podcast(&mut self, _podcast: &HomePodcast) {}
+}
+
+/// The type of channel that authored a playlist.
+#[derive(PartialEq, Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum HomeAuthorKind {
+    /// An official artist channel.
+    Artist,
+    /// A regular user's channel.
+    UserChannel,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct HomeArtist {
+    pub title: String,
+    pub channel_id: ArtistChannelID<'static>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscribers: Option<String>,
+    /// Params of the card's subscribe button, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscribe_params: Option<String>,
+    /// Params of the card's unsubscribe button, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsubscribe_params: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+    /// The full subtitle as displayed on the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// True if the item is in a shelf of items from the user's library.
+    #[serde(default)]
+    pub from_library: bool,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct HomeWatchPlaylist {
+    pub title: String,
+    pub playlist_id: PlaylistID<'static>,
+    pub thumbnails: Vec<Thumbnail>,
+    /// The full subtitle as displayed on the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// Opaque params sent by the app when logging impressions or starting
+    /// playback of the mix.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking_params: Option<String>,
+    /// True if the item is in a shelf of items from the user's library.
+    #[serde(default)]
+    pub from_library: bool,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct HomePodcast {
+    pub title: String,
+    pub podcast_id: PodcastID<'static>,
+    /// The channel that publishes the podcast, if it's linked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<ParsedSongArtist>,
+    /// Number of episodes, if shown on the card, e.g from "120 episodes".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub episode_count: Option<u32>,
+    pub thumbnails: Vec<Thumbnail>,
+    /// The full subtitle as displayed on the card.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+    /// True if the item is in a shelf of items from the user's library.
+    #[serde(default)]
+    pub from_library: bool,
+}
+
+impl MetricLexicon {
+    pub fn new(
+        views: impl IntoIterator<Item = impl Into<String>>,
+        subscribers: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self {
+            views: views.into_iter().map(Into::into).collect(),
+            subscribers: subscribers.into_iter().map(Into::into).collect(),
+        }
+    }
+    /// Get the built-in words for a language code such as "fr" or "de-AT".
+    /// Returns None if the language is not built-in.
+    pub fn for_locale(locale: &str) -> Option<Self> {
+        let language = locale.split(['-', '_']).next()?;
+        METRIC_TOKENS
+            .iter()
+            .find(|(code, _, _)| code.eq_ignore_ascii_case(language))
+            .map(|(_, views, subscribers)| Self::new([*views], [*subscribers]))
+    }
+}
+
+/// Options controlling how the home feed is parsed.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct HomeParseOptions {
+    /// Keep the full subtitle of each item. Disabling this reduces memory use
+    /// when parsing large feeds - fields derived from the subtitle, such as
+    /// artists and year, are still parsed.
+    pub keep_raw_subtitle: bool,
+    /// Words used to identify view and subscriber counts.
+    pub lexicon: MetricLexicon,
+    /// Record the names of skipped renderers in
+    /// [`HomeSections::unknown_renderers`]. Intended for debugging.
+    pub audit_renderers: bool,
+    /// Parse the mood chips at the top of the feed. Disabling this leaves
+    /// [`HomeSections::chips`] empty.
+    pub parse_chips: bool,
+    /// Parse at most this many items of each section, e.g for a preview
+    /// showing only the start of each shelf. Items past the limit are skipped
+    /// without being parsed.
+    pub max_items_per_section: Option<usize>,
+    /// Keep only the smallest thumbnail of each item, to reduce memory use.
+    /// Section header thumbnails are kept in full.
+    pub smallest_thumbnail_only: bool,
+}
+
+impl Default for HomeParseOptions {
+    fn default() -> Self {
+        Self {
+            keep_raw_subtitle: true,
+            lexicon: MetricLexicon::default(),
+            audit_renderers: false,
+            parse_chips: true,
+            max_items_per_section: None,
+            smallest_thumbnail_only: false,
+        }
+    }
+}
+
+impl CachedHomeSections {
+    /// Wrap a feed that has just been fetched.
+    pub fn new(data: HomeSections) -> Self {
+        Self::new_at(data, std::time::SystemTime::now())
+    }
+    /// Wrap a feed that was fetched at `fetched_at`.
+    pub fn new_at(data: HomeSections, fetched_at: std::time::SystemTime) -> Self {
+        Self { fetched_at, data }
+    }
+    /// True if the feed was fetched more than `duration` ago.
+    /// A feed with a fetch time in the future, e.g due to the system clock
+    /// changing, is never considered older.
+    pub fn is_older_than(&self, duration: std::time::Duration) -> bool {
+        self.fetched_at
+            .elapsed()
+            .is_ok_and(|elapsed| elapsed > duration)
+    }
+}
+
+impl Default for MetricLexicon {
+    fn default() -> Self {
+        Self::new(
+            METRIC_TOKENS.iter().map(|(_, views, _)| *views),
+            METRIC_TOKENS.iter().map(|(_, _, subscribers)| *subscribers),
+        )
+    }
+}
+
+impl HomeSections {
+    pub fn new(chips: Vec<HomeMoodChip>, sections: Vec<HomeSection>) -> Self {
+        Self {
+            chips,
+            sections,
+            active_chip: None,
+            more_moods_params: None,
+            warnings: Vec::new(),
+            unknown_renderers: Vec::new(),
+        }
+    }
+    /// As for [`HomeSections::new`], but returns an error if there are no
+    /// chips and no sections, as this usually indicates a broken or
+    /// unauthenticated response instead of a genuinely empty feed.
+    pub fn try_new(chips: Vec<HomeMoodChip>, sections: Vec<HomeSection>) -> Result<Self> {
+        if chips.is_empty() && sections.is_empty() {
+            return Err(Error::empty_response());
+        }
+        Ok(Self::new(chips, sections))
+    }
+    pub fn from_sections(sections: Vec<HomeSection>) -> Self {
+        Self::new(Vec::new(), sections)
+    }
+    /// Summarise the number of sections, chips and items in the feed.
+    pub fn stats(&self) -> HomeStats {
+        let mut stats = HomeStats {
+            sections: self.sections.len(),
+            chips: self.chips.len(),
+            ..Default::default()
+        };
+        for item in self.items() {
+            stats.total_items += 1;
+            *stats.items_by_kind.entry(item.kind()).or_default() += 1;
+            if !matches!(
+                item,
+                HomeContent::Song(_) | HomeContent::Video(_) | HomeContent::Album(_)
+            ) {
+                continue;
+            }
+            if item.is_explicit() {
+                stats.explicit_items += 1;
+            } else {
+                stats.clean_items += 1;
+            }
+        }
+        stats
+    }
+    /// Append a continuation page to the feed. Chips are only taken from
+    /// `page` if the feed doesn't already have any, since they are only
+    /// returned with the first page. Positions of sections in `page` are
+    /// offset so that they continue on from the existing sections.
+    pub(crate) fn extend_from_page(&mut self, page: HomeSections) {
+        if self.chips.is_empty() {
+            self.chips = page.chips;
+        }
+        if self.active_chip.is_none() {
+            self.active_chip = page.active_chip;
+        }
+        if self.more_moods_params.is_none() {
+            self.more_moods_params = page.more_moods_params;
+        }
+        let offset = self.sections.len();
+        self.sections
+            .extend(page.sections.into_iter().map(|section| HomeSection {
+                position: section.position + offset,
+                ..section
+            }));
+        self.warnings.extend(page.warnings);
+        for name in page.unknown_renderers {
+            if !self.unknown_renderers.contains(&name) {
+                self.unknown_renderers.push(name);
+            }
+        }
+    }
+    /// Iterate over the items of all sections, in order.
+    pub fn items(&self) -> impl Iterator<Item = &HomeContent> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.contents.iter())
+    }
+    /// Flatten the feed into a single list, with each section's header
+    /// followed by its items, e.g for display in a list instead of a grid.
+    pub fn as_rows(&self) -> Vec<HomeRow<'_>> {
+        self.sections
+            .iter()
+            .flat_map(|section| {
+                let header = HomeRow::Header {
+                    title: &section.title,
+                    strapline: section.strapline.as_deref(),
+                };
+                std::iter::once(header).chain(section.contents.iter().map(HomeRow::Item))
+            })
+            .collect()
+    }
+    /// Get a copy of the feed with each section truncated to its first
+    /// `items_per_section` items, e.g for a compact preview of the feed.
+    /// Unlike [`HomeParseOptions::max_items_per_section`], this applies to a
+    /// feed that has already been parsed. Items past the limit are not cloned.
+    pub fn preview(&self, items_per_section: usize) -> HomeSections {
+        let sections = self
+            .sections
+            .iter()
+            .map(|section| HomeSection {
+                title: section.title.clone(),
+                strapline: section.strapline.clone(),
+                thumbnails: section.thumbnails.clone(),
+                animated_thumbnail: section.animated_thumbnail.clone(),
+                more_params: section.more_params.clone(),
+                more_label: section.more_label.clone(),
+                shuffle_params: section.shuffle_params.clone(),
+                tracking_params: section.tracking_params.clone(),
+                position: section.position,
+                contents: section
+                    .contents
+                    .iter()
+                    .take(items_per_section)
+                    .cloned()
+                    .collect(),
+            })
+            .collect();
+        HomeSections {
+            chips: self.chips.clone(),
+            sections,
+            active_chip: self.active_chip.clone(),
+            more_moods_params: self.more_moods_params.clone(),
+            warnings: self.warnings.clone(),
+            unknown_renderers: self.unknown_renderers.clone(),
+        }
+    }
+    /// Mutably iterate over the items of all sections, in order.
+    pub fn items_mut(&mut self) -> impl Iterator<Item = &mut HomeContent> {
+        self.sections
+            .iter_mut()
+            .flat_map(|section| section.contents.iter_mut())
+    }
+    /// Get the channel id of every distinct artist in the feed, from songs,
+    /// videos, albums and artist cards, in the order they first appear.
+    /// Playlist authors and podcast publishers are not included.
+    pub fn artist_ids(&self) -> Vec<ArtistChannelID<'static>> {
+        let mut seen = HashSet::new();
+        let mut ids = Vec::new();
+        for item in self.items() {
+            let item_ids: Vec<&ArtistChannelID<'static>> = match item {
+                HomeContent::Song(song) => {
+                    song.artists.iter().filter_map(|a| a.id.as_ref()).collect()
+                }
+                HomeContent::Video(video) => {
+                    video.artists.iter().filter_map(|a| a.id.as_ref()).collect()
+                }
+                HomeContent::Album(album) => {
+                    album.artists.iter().filter_map(|a| a.id.as_ref()).collect()
+                }
+                HomeContent::Artist(artist) => vec![&artist.channel_id],
+                HomeContent::Playlist(_)
+                | HomeContent::WatchPlaylist(_)
+                | HomeContent::Podcast(_) => Vec::new(),
+            };
+            for id in item_ids {
+                if seen.insert(id) {
+                    ids.push(id.clone());
+                }
+            }
+        }
+        ids
+    }
+    /// Compute a hash of the structure of the feed - the section titles, and
+    /// the kind and id of each item - to detect if the feed has changed
+    /// between fetches, e.g to avoid re-rendering it.
+    /// Subtitles, thumbnails and other fields that can change without the
+    /// feed itself changing are ignored.
+    /// The hash is only stable within a single build of the library, so it
+    /// should not be persisted.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = std::hash::DefaultHasher::new();
+        for section in &self.sections {
+            section.title.hash(&mut hasher);
+            section.contents.len().hash(&mut hasher);
+            for item in &section.contents {
+                item.cache_key().hash(&mut hasher);
+            }
+        }
+        hasher.finish()
+    }
+    /// Sort the sections using `cmp`. The sort is stable, so sections that
+    /// compare equal keep the order returned by the server.
+    pub fn sort_sections_by<F>(&mut self, cmp: F)
+    where
+        F: FnMut(&HomeSection, &HomeSection) -> std::cmp::Ordering,
+    {
+        self.sections.sort_by(cmp)
+    }
+    /// Sort the sections so that their kinds appear in the order of `order`.
+    /// Sections of kinds not contained in `order` are placed last. Sections of
+    /// the same kind keep the order returned by the server.
+    pub fn sort_by_kind_priority(&mut self, order: &[HomeSectionKind]) {
+        self.sections.sort_by_cached_key(|section| {
+            let kind = section.section_kind();
+            order.iter().position(|k| *k == kind).unwrap_or(order.len())
+        })
+    }
+    /// Merge runs of adjacent sections that each contain a single item into one
+    /// section, as InnerTube sometimes splits a topic into several shelves.
+    /// If `by_title_prefix` is set, sections are only merged if their titles
+    /// start with the same words, and the merged section is titled with the
+    /// shared words, e.g "Because you like". Otherwise the merged section
+    /// keeps the title of the first section.
+    /// The order of items is preserved, and the merged section keeps the other
+    /// fields of the first section in the run.
+    pub fn coalesce_single_item_sections(&mut self, by_title_prefix: bool) {
+        let mut coalesced: Vec<HomeSection> = Vec::with_capacity(self.sections.len());
+        // Whether the last coalesced section can be merged into, and the title
+        // words shared by the sections merged so far.
+        let mut run: Option<Vec<String>> = None;
+        for section in std::mem::take(&mut self.sections) {
+            if section.contents.len() != 1 {
+                run = None;
+                coalesced.push(section);
+                continue;
+            }
+            let words: Vec<String> = section.title.split_whitespace().map(String::from).collect();
+            if let Some(shared) = &mut run
+                && let Some(last) = coalesced.last_mut()
+            {
+                let common = shared
+                    .iter()
+                    .zip(&words)
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                if !by_title_prefix || common > 0 {
+                    if by_title_prefix {
+                        shared.truncate(common);
+                        last.title = shared.join(" ");
+                    }
+                    last.contents.extend(section.contents);
+                    continue;
+                }
+            }
+            run = Some(words);
+            coalesced.push(section);
+        }
+        self.sections = coalesced;
+    }
+    /// Get the first section whose title is exactly `title`.
+    /// Note that section titles are localised.
+    pub fn find_section(&self, title: &str) -> Option<&HomeSection> {
+        self.sections.iter().find(|section| section.title == title)
+    }
+    /// Get the position of the first item with the given key, as the index of
+    /// its section and its index within that section, e.g to scroll to an
+    /// item linked to from elsewhere.
+    pub fn locate(&self, key: &HomeContentKey) -> Option<(usize, usize)> {
+        self.sections
+            .iter()
+            .enumerate()
+            .find_map(|(section_idx, section)| {
+                section
+                    .contents
+                    .iter()
+                    .position(|item| item.cache_key() == *key)
+                    .map(|item_idx| (section_idx, item_idx))
+            })
+    }
+    /// Get the shelf of items the user has played recently, if the feed has
+    /// one. This is the first section of kind
+    /// [`HomeSectionKind::ListenAgain`].
+    pub fn listen_again(&self) -> Option<&HomeSection> {
+        self.sections
+            .iter()
+            .find(|section| section.section_kind() == HomeSectionKind::ListenAgain)
+    }
+    /// Get the first section whose title contains `substr`, ignoring case.
+    /// As section titles are localised, this is generally more robust than
+    /// [`HomeSections::find_section`].
+    pub fn find_section_contains(&self, substr: &str) -> Option<&HomeSection> {
+        let substr = substr.to_lowercase();
+        self.sections
+            .iter()
+            .find(|section| section.title.to_lowercase().contains(&substr))
+    }
+    /// Get the first chip whose title matches `title`, ignoring case and
+    /// accents, e.g "energia" matches "Energía".
+    /// Useful for selecting a chip from user input, as chip titles are
+    /// localised.
+    pub fn find_chip(&self, title: &str) -> Option<&HomeMoodChip> {
+        let title = normalise_title(title);
+        self.chips
+            .iter()
+            .find(|chip| normalise_title(&chip.title) == title)
+    }
+}
+
+impl HomeContentKind {
+    /// Get the tag used for this kind when serializing a [`HomeContent`], e.g
+    /// "watch_playlist".
+    pub fn as_tag(&self) -> &'static str {
+        match self {
+            HomeContentKind::Song => "song",
+            HomeContentKind::Video => "video",
+            HomeContentKind::Album => "album",
+            HomeContentKind::Playlist => "playlist",
+            HomeContentKind::Artist => "artist",
+            HomeContentKind::WatchPlaylist => "watch_playlist",
+            HomeContentKind::Podcast => "podcast",
+        }
+    }
+    /// Get the kind from a tag as returned by [`HomeContentKind::as_tag`].
+    /// Returns None if the tag is not recognised.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "song" => Some(HomeContentKind::Song),
+            "video" => Some(HomeContentKind::Video),
+            "album" => Some(HomeContentKind::Album),
+            "playlist" => Some(HomeContentKind::Playlist),
+            "artist" => Some(HomeContentKind::Artist),
+            "watch_playlist" => Some(HomeContentKind::WatchPlaylist),
+            "podcast" => Some(HomeContentKind::Podcast),
+            _ => None,
+        }
+    }
+}
+
+impl HomeContent {
+    pub fn kind(&self) -> HomeContentKind {
+        match self {
+            HomeContent::Song(_) => HomeContentKind::Song,
+            HomeContent::Video(_) => HomeContentKind::Video,
+            HomeContent::Album(_) => HomeContentKind::Album,
+            HomeContent::Playlist(_) => HomeContentKind::Playlist,
+            HomeContent::Artist(_) => HomeContentKind::Artist,
+            HomeContent::WatchPlaylist(_) => HomeContentKind::WatchPlaylist,
+            HomeContent::Podcast(_) => HomeContentKind::Podcast,
+        }
+    }
+    pub fn as_song(&self) -> Option<&HomeSong> {
+        match self {
+            HomeContent::Song(song) => Some(song),
+            _ => None,
+        }
+    }
+    pub fn as_video(&self) -> Option<&HomeVideo> {
+        match self {
+            HomeContent::Video(video) => Some(video),
+            _ => None,
+        }
+    }
+    pub fn as_album(&self) -> Option<&HomeAlbum> {
+        match self {
+            HomeContent::Album(album) => Some(album),
+            _ => None,
+        }
+    }
+    pub fn as_playlist(&self) -> Option<&HomePlaylist> {
+        match self {
+            HomeContent::Playlist(playlist) => Some(playlist),
+            _ => None,
+        }
+    }
+    pub fn as_artist(&self) -> Option<&HomeArtist> {
+        match self {
+            HomeContent::Artist(artist) => Some(artist),
+            _ => None,
+        }
+    }
+    pub fn as_watch_playlist(&self) -> Option<&HomeWatchPlaylist> {
+        match self {
+            HomeContent::WatchPlaylist(watch_playlist) => Some(watch_playlist),
+            _ => None,
+        }
+    }
+    pub fn as_podcast(&self) -> Option<&HomePodcast> {
+        match self {
+            HomeContent::Podcast(podcast) => Some(podcast),
+            _ => None,
+        }
+    }
+    /// Get the first artist of a song, video or album, the first author of a
+    /// playlist, or the publisher of a podcast.
+    pub fn primary_artist(&self) -> Option<&ParsedSongArtist> {
+        match self {
+            HomeContent::Song(song) => song.artists.first(),
+            HomeContent::Video(video) => video.artists.first(),
+            HomeContent::Album(album) => album.artists.first(),
+            HomeContent::Playlist(playlist) => playlist.author.first(),
+            HomeContent::Podcast(podcast) => podcast.publisher.as_ref(),
+            HomeContent::Artist(_) | HomeContent::WatchPlaylist(_) => None,
+        }
+    }
+    /// Get a key identifying this item, ignoring its other fields. Two items
+    /// with the same kind and id have the same key.
+    pub fn cache_key(&self) -> HomeContentKey {
+        let id = match self {
+            HomeContent::Song(song) => song.video_id.get_raw(),
+            HomeContent::Video(video) => video.video_id.get_raw(),
+            HomeContent::Album(album) => album.album_id.get_raw(),
+            HomeContent::Playlist(playlist) => playlist.playlist_id.get_raw(),
+            HomeContent::Artist(artist) => artist.channel_id.get_raw(),
+            HomeContent::WatchPlaylist(watch_playlist) => watch_playlist.playlist_id.get_raw(),
+            HomeContent::Podcast(podcast) => podcast.podcast_id.get_raw(),
+        };
+        HomeContentKey {
+            kind: self.kind(),
+            id: id.to_string(),
+        }
+    }
+    /// True if this is a song, video or album that is known to be explicit.
+    pub fn is_explicit(&self) -> bool {
+        match self {
+            HomeContent::Song(song) => song.is_explicit(),
+            HomeContent::Video(video) => video.is_explicit(),
+            HomeContent::Album(album) => album.is_explicit(),
+            _ => false,
+        }
+    }
+    /// Call the method of `visitor` corresponding to the kind of this item.
+    pub fn accept<V: HomeContentVisitor + ?Sized>(&self, visitor: &mut V) {
+        match self {
+            HomeContent::Song(song) => visitor.visit_song(song),
+            HomeContent::Video(video) => visitor.visit_video(video),
+            HomeContent::Album(album) => visitor.visit_album(album),
+            HomeContent::Playlist(playlist) => visitor.visit_playlist(playlist),
+            HomeContent::Artist(artist) => visitor.visit_artist(artist),
+            HomeContent::WatchPlaylist(watch_playlist) => {
+                visitor.visit_watch_playlist(watch_playlist)
+            }
+            HomeContent::Podcast(podcast) => visitor.visit_podcast(podcast),
+        }
+    }
+    fn mark_from_library(&mut self) {
+        match self {
+            HomeContent::Song(song) => song.from_library = true,
+            HomeContent::Video(video) => video.from_library = true,
+            HomeContent::Album(album) => album.from_library = true,
+            HomeContent::Playlist(playlist) => playlist.from_library = true,
+            HomeContent::Artist(artist) => artist.from_library = true,
+            HomeContent::WatchPlaylist(watch_playlist) => watch_playlist.from_library = true,
+            HomeContent::Podcast(podcast) => podcast.from_library = true,
+        }
+    }
+    pub fn title(&self) -> &str {
+        match self {
+            HomeContent::Song(song) => &song.title,
+            HomeContent::Video(video) => &video.title,
+            HomeContent::Album(album) => &album.title,
+            HomeContent::Playlist(playlist) => &playlist.title,
+            HomeContent::Artist(artist) => &artist.title,
+            HomeContent::WatchPlaylist(watch_playlist) => &watch_playlist.title,
+            HomeContent::Podcast(podcast) => &podcast.title,
+        }
+    }
+    /// Compare two items alphabetically by title, ignoring case and accents on
+    /// common latin characters. Titles that only differ by case or accents
+    /// fall back to comparing the titles exactly, so that the order is
+    /// consistent.
+    pub fn cmp_by_title(&self, other: &HomeContent) -> std::cmp::Ordering {
+        normalise_title(self.title())
+            .cmp(&normalise_title(other.title()))
+            .then_with(|| self.title().cmp(other.title()))
+    }
+    fn retain_smallest_thumbnail(&mut self) {
+        let thumbnails = match self {
+            HomeContent::Song(song) => &mut song.thumbnails,
+            HomeContent::Video(video) => &mut video.thumbnails,
+            HomeContent::Album(album) => &mut album.thumbnails,
+            HomeContent::Playlist(playlist) => &mut playlist.thumbnails,
+            HomeContent::Artist(artist) => &mut artist.thumbnails,
+            HomeContent::WatchPlaylist(watch_playlist) => &mut watch_playlist.thumbnails,
+            HomeContent::Podcast(podcast) => &mut podcast.thumbnails,
+        };
+        if let Some(smallest) = thumbnails.iter().min_by_key(|t| t.width).cloned() {
+            *thumbnails = vec![smallest];
+        }
+    }
+    pub fn thumbnails(&self) -> &[Thumbnail] {
+        match self {
+            HomeContent::Song(song) => &song.thumbnails,
+            HomeContent::Video(video) => &video.thumbnails,
+            HomeContent::Album(album) => &album.thumbnails,
+            HomeContent::Playlist(playlist) => &playlist.thumbnails,
+            HomeContent::Artist(artist) => &artist.thumbnails,
+            HomeContent::WatchPlaylist(watch_playlist) => &watch_playlist.thumbnails,
+            HomeContent::Podcast(podcast) => &podcast.thumbnails,
+        }
+    }
+    /// Get the shape of the item's thumbnail, to lay out cards of mixed kinds.
+    /// This is taken from the dimensions of the largest thumbnail, falling
+    /// back to the usual shape for the kind of item if they aren't known.
+    pub fn thumbnail_aspect(&self) -> ThumbnailAspect {
+        let largest = self
+            .thumbnails()
+            .iter()
+            // Widened to avoid overflow with untrusted dimensions.
+            .max_by_key(|t| u128::from(t.width) * u128::from(t.height))
+            .filter(|t| t.width > 0 && t.height > 0);
+        match largest {
+            // Allow for thumbnails that are cropped slightly.
+            Some(t) if u128::from(t.width) * 4 > u128::from(t.height) * 5 => ThumbnailAspect::Wide,
+            Some(_) => ThumbnailAspect::Square,
+            None if self.kind() == HomeContentKind::Video => ThumbnailAspect::Wide,
+            None => ThumbnailAspect::Square,
+        }
+    }
+    /// True if the item's thumbnail is displayed as a circle, and so should be
+    /// clipped. This is only the case for artists.
+    pub fn thumbnail_is_circular(&self) -> bool {
+        matches!(self, HomeContent::Artist(_))
+    }
+    /// Download the bytes of the thumbnail that best fits `target_width` - the
+    /// smallest thumbnail at least as wide as `target_width`, or the largest
+    /// thumbnail if none are wide enough.
+    /// Redirects are followed according to the policy of `client`.
+    /// Returns an
+    /// [`ErrorKind::NoThumbnail`](crate::error::ErrorKind::NoThumbnail)
+    /// error, which is not retryable, if the item has no thumbnails.
+    #[cfg(feature = "thumbnail-fetch")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thumbnail-fetch")))]
+    pub async fn fetch_thumbnail(
+        &self,
+        client: &reqwest::Client,
+        target_width: u32,
+    ) -> Result<Vec<u8>> {
+        let thumbnails = self.thumbnails();
+        let best_fit = thumbnails
+            .iter()
+            .filter(|t| t.width >= target_width as u64)
+            .min_by_key(|t| t.width)
+            .or_else(|| thumbnails.iter().max_by_key(|t| t.width))
+            .ok_or_else(Error::no_thumbnail)?;
+        let bytes = client
+            .get(&best_fit.url)
+            .timeout(THUMBNAIL_FETCH_TIMEOUT)
+            .send()
+            .await?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        Ok(bytes.to_vec())
+    }
+    /// Compare the structural fields of two items (title, ids, artists and
+    /// explicit status), ignoring the localised subtitle and any fields
+    /// derived from it.
+    #[cfg(any(test, feature = "test-util"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+    pub fn eq_ignoring_subtitle(&self, other: &Self) -> bool {
+        match (self, other) {
+            (HomeContent::Song(a), HomeContent::Song(b)) => {
+                a.title == b.title
+                    && a.video_id == b.video_id
+                    && a.artists == b.artists
+                    && a.album == b.album
+                    && a.explicit == b.explicit
+            }
+            (HomeContent::Video(a), HomeContent::Video(b)) => {
+                a.title == b.title
+                    && a.video_id == b.video_id
+                    && a.artists == b.artists
+                    && a.explicit == b.explicit
+            }
+            (HomeContent::Album(a), HomeContent::Album(b)) => {
+                a.title == b.title
+                    && a.album_id == b.album_id
+                    && a.artists == b.artists
+                    && a.explicit == b.explicit
+            }
+            (HomeContent::Playlist(a), HomeContent::Playlist(b)) => {
+                a.title == b.title && a.playlist_id == b.playlist_id && a.author == b.author
+            }
+            (HomeContent::Artist(a), HomeContent::Artist(b)) => {
+                a.title == b.title && a.channel_id == b.channel_id
+            }
+            (HomeContent::WatchPlaylist(a), HomeContent::WatchPlaylist(b)) => {
+                a.title == b.title && a.playlist_id == b.playlist_id
+            }
+            (HomeContent::Podcast(a), HomeContent::Podcast(b)) => {
+                a.title == b.title && a.podcast_id == b.podcast_id && a.publisher == b.publisher
+            }
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for HomeContent {
+    /// One line summary of the item, e.g "Song: Teardrop — Massive Attack".
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (kind, title) = match self {
+            HomeContent::Song(song) => ("Song", &song.title),
+            HomeContent::Video(video) => ("Video", &video.title),
+            HomeContent::Album(album) => ("Album", &album.title),
+            HomeContent::Playlist(playlist) => ("Playlist", &playlist.title),
+            HomeContent::Artist(artist) => ("Artist", &artist.title),
+            HomeContent::WatchPlaylist(watch_playlist) => ("Mix", &watch_playlist.title),
+            HomeContent::Podcast(podcast) => ("Podcast", &podcast.title),
+        };
+        write!(f, "{kind}: {title}")?;
+        if let Some(artist) = self.primary_artist() {
+            write!(f, " — {}", artist.name)?;
+        }
+        if let HomeContent::Album(HomeAlbum {
+            year: Some(year), ..
+        }) = self
+        {
+            write!(f, " ({year})")?;
+        }
+        Ok(())
+    }
+}
+
+impl HomeMoodChip {
+    /// Get a query for the home feed, filtered using this mood.
+    pub fn home_query(&self) -> GetHomeQuery<'_> {
+        GetHomeQuery::new().with_mood(&self.params)
+    }
+}
+
+impl HomeSong {
+    /// True if the song is known to be explicit.
+    pub fn is_explicit(&self) -> bool {
+        self.explicit == Some(Explicit::IsExplicit)
+    }
+    /// Get a query for the full details of the song's album, if it's known.
+    pub fn album_query(&self) -> Option<GetAlbumQuery<'_>> {
+        self.album
+            .as_ref()
+            .map(|album| GetAlbumQuery::new(&album.id))
+    }
+}
+
+impl HomeVideo {
+    /// True if the video is known to be explicit.
+    pub fn is_explicit(&self) -> bool {
+        self.explicit == Some(Explicit::IsExplicit)
+    }
+    /// Get the view count as a number, e.g 1200000 from '1.2M'. As the count
+    /// is abbreviated on the card it's only approximate.
+    pub fn view_count(&self) -> Option<u64> {
+        self.views
+            .as_deref()
+            .and_then(parse_localized_number)
+            .map(|count| count.round() as u64)
+    }
+}
+
+impl HomeAlbum {
+    /// True if the album is known to be explicit.
+    pub fn is_explicit(&self) -> bool {
+        self.explicit == Some(Explicit::IsExplicit)
+    }
+    /// Get a query for the full details of this album.
+    pub fn browse_query(&self) -> GetAlbumQuery<'_> {
+        GetAlbumQuery::new(&self.album_id)
+    }
+}
+
+impl HomePlaylist {
+    /// Get a query for the full details of this playlist.
+    pub fn browse_query(&self) -> GetPlaylistDetailsQuery<'static> {
+        // Playlists are browsed using the playlist id prefixed with 'VL'.
+        GetPlaylistDetailsQuery::new(PlaylistID::from_raw(format!(
+            "VL{}",
+            self.playlist_id.get_raw()
+        )))
+    }
+}
+
+impl HomeArtist {
+    /// Get a query for the full details of this artist.
+    pub fn browse_query(&self) -> GetArtistQuery<'_> {
+        GetArtistQuery::new(&self.channel_id)
+    }
+    /// Get the subscriber count as a number, e.g 1200000 from '1.2M'. As the
+    /// count is abbreviated on the card it's only approximate.
+    pub fn subscriber_count(&self) -> Option<u64> {
+        self.subscribers
+            .as_deref()
+            .and_then(parse_localized_number)
+            .map(|count| count.round() as u64)
+    }
+}
+
+impl HomeSection {
+    /// Get the first header thumbnail, if the section has one.
+    pub fn thumbnail(&self) -> Option<&Thumbnail> {
+        self.thumbnails.first()
+    }
+    /// Iterate over the items of this section of the given kind, e.g only the
+    /// songs of a mixed shelf.
+    pub fn contents_of_kind(&self, kind: HomeContentKind) -> impl Iterator<Item = &HomeContent> {
+        self.contents.iter().filter(move |item| item.kind() == kind)
+    }
+    /// Split the items of this section into those that can be played directly
+    /// (songs, videos and watch playlists), and those that can only be
+    /// browsed (albums, playlists, artists and podcasts).
+    /// Order within each bucket is preserved.
+    pub fn partition(&self) -> (Vec<&HomeContent>, Vec<&HomeContent>) {
+        self.contents.iter().partition(|item| match item {
+            HomeContent::Song(_) | HomeContent::Video(_) | HomeContent::WatchPlaylist(_) => true,
+            HomeContent::Album(_)
+            | HomeContent::Playlist(_)
+            | HomeContent::Artist(_)
+            | HomeContent::Podcast(_) => false,
+        })
+    }
+    /// Sort the items of this section alphabetically by title, see
+    /// [`HomeContent::cmp_by_title`].
+    pub fn sort_contents_by_title(&mut self) {
+        self.contents
+            .sort_by_cached_key(|item| (normalise_title(item.title()), item.title().to_string()))
+    }
+    /// Download the best fitting thumbnail for each item in the section, with
+    /// at most `concurrency` requests in flight at once.
+    /// Results are returned in the same order as `contents`, and a failure to
+    /// fetch one thumbnail does not affect the others.
+    #[cfg(feature = "thumbnail-fetch")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "thumbnail-fetch")))]
+    pub async fn prefetch_thumbnails(
+        &self,
+        client: &reqwest::Client,
+        target_width: u32,
+        concurrency: usize,
+    ) -> Vec<Result<Vec<u8>>> {
+        use futures::StreamExt;
+        let mut results =
+            futures::stream::iter(self.contents.iter().enumerate())
+                .map(|(idx, item)| async move {
+                    (idx, item.fetch_thumbnail(client, target_width).await)
+                })
+                .buffer_unordered(concurrency.max(1))
+                .collect::<Vec<_>>()
+                .await;
+        results.sort_by_key(|(idx, _)| *idx);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+    /// Heuristically determine the kind of this section.
+    /// As titles are localised, this is based primarily on the structure of
+    /// the section, using the title only as a hint where the structure is
+    /// ambiguous.
+    pub fn section_kind(&self) -> HomeSectionKind {
+        let total = self.contents.len();
+        if total == 0 {
+            return HomeSectionKind::Other;
+        }
+        let watch_playlists = self
+            .contents
+            .iter()
+            .filter(|item| matches!(item, HomeContent::WatchPlaylist(_)))
+            .count();
+        let tracks = self
+            .contents
+            .iter()
+            .filter(|item| matches!(item, HomeContent::Song(_) | HomeContent::Video(_)))
+            .count();
+        if watch_playlists * 2 > total {
+            return HomeSectionKind::MoodMix;
+        }
+        // Personalised shelves display the user's name as the strapline.
+        if self.strapline.is_some() {
+            return HomeSectionKind::ListenAgain;
+        }
+        let title = self.title.to_lowercase();
+        let title_contains = |tokens: &[&str]| tokens.iter().any(|t| title.contains(t));
+        if title_contains(&LISTEN_AGAIN_TOKENS) {
+            HomeSectionKind::ListenAgain
+        } else if title_contains(&QUICK_PICKS_TOKENS) || tracks == total {
+            HomeSectionKind::QuickPicks
+        } else if title_contains(&MIX_TOKENS) && watch_playlists > 0 {
+            HomeSectionKind::MoodMix
+        } else {
+            HomeSectionKind::Recommended
+        }
+    }
+}
+
+impl<'a> ParseFromContinuable<GetHomeQuery<'a>> for HomeSections {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, err(Display)))]
+    fn parse_from_continuable(
+        p: ProcessedResult<GetHomeQuery<'a>>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
+        let mood_params = p
+            .query
+            .get_mood_params()
+            .map(|params| params.get_raw().to_string());
+        let options = p.query.get_parse_options();
+        let json_crawler = JsonCrawlerOwned::from(p);
+        if is_sign_in_required(&json_crawler) {
+            return Err(Error::auth_required());
+        }
+        let section_list =
+            json_crawler.navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, "/sectionListRenderer"))?;
+        let (mut home, continuation_params) = parse_home_contents(section_list, options)?;
+        // The chip returned for the active mood may have different params to the
+        // ones used to filter, so fall back to the selected chip.
+        home.active_chip = mood_params.and_then(|mood_params| {
+            home.chips
+                .iter()
+                .find(|chip| chip.params.get_raw() == mood_params)
+                .or_else(|| home.chips.iter().find(|chip| chip.selected))
+                .cloned()
+        });
+        Ok((home, continuation_params))
+    }
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(skip_all, err(Display), fields(sections, skipped))
+    )]
+    fn parse_continuation(
+        p: ProcessedResult<GetContinuationsQuery<'_, GetHomeQuery<'a>>>,
+    ) -> Result<(Self, Option<ContinuationParams<'static>>)> {
+        let options = p.query.get_query().get_parse_options();
+        let json_crawler = JsonCrawlerOwned::from(p);
+        // Once the end of the feed is reached, no further continuation contents
+        // are returned.
+        let Ok(mut section_list) = json_crawler.navigate_pointer(SECTION_LIST_CONTINUATION) else {
+            return Ok((HomeSections::default(), None));
+        };
+        let continuation_params = section_list.take_value_pointer(CONTINUATION_PARAMS).ok();
+        let mut warnings = Vec::new();
+        let mut unknown_renderers = Vec::new();
+        // A continuation page may contain no sections, but still have params for
+        // the next page. This is not the end of the feed.
+        let sections = match section_list.borrow_pointer("/contents") {
+            Ok(contents) => {
+                parse_mixed_content(contents, &mut warnings, &mut unknown_renderers, options)?
+            }
+            Err(_) => Vec::new(),
+        };
+        let home = HomeSections {
+            warnings,
+            unknown_renderers,
+            ..HomeSections::from_sections(sections)
+        };
+        #[cfg(feature = "tracing")]
+        record_home_counts(&home);
+        Ok((home, continuation_params))
+    }
+}
+
+impl ParseFrom<GetExploreQuery> for HomeSections {
+    fn parse_from(p: ProcessedResult<GetExploreQuery>) -> Result<Self> {
+        let json_crawler = JsonCrawlerOwned::from(p);
+        let section_list =
+            json_crawler.navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, "/sectionListRenderer"))?;
+        parse_home_contents(section_list, &HomeParseOptions::default())
+            .map(|(sections, _)| sections)
+    }
+}
+
+impl ParseFrom<GetHomeChipsQuery> for Vec<HomeMoodChip> {
+    fn parse_from(p: ProcessedResult<GetHomeChipsQuery>) -> Result<Self> {
+        let json_crawler = JsonCrawlerOwned::from(p);
+        if is_sign_in_required(&json_crawler) {
+            return Err(Error::auth_required());
+        }
+        let mut section_list =
+            json_crawler.navigate_pointer(concatcp!(SINGLE_COLUMN_TAB, "/sectionListRenderer"))?;
+        // Chip cloud is not present when the feed is empty.
+        match section_list.borrow_pointer(CHIP_CLOUD_CHIPS) {
+            Ok(chips) => parse_chip_cloud(chips).map(|(chips, _)| chips),
+            Err(_) => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Accumulate a stream of home feed pages, yielding the whole feed so far after
+/// each page.
+pub(crate) fn home_snapshots<'a>(
+    pages: impl Stream<Item = Result<HomeSections>> + 'a,
+) -> impl Stream<Item = Result<HomeSections>> + 'a {
+    pages.scan(HomeSections::default(), |feed, page| {
+        let snapshot = page.map(|page| {
+            feed.extend_from_page(page);
+            feed.clone()
+        });
+        futures::future::ready(Some(snapshot))
+    })
+}
+
+/// When not signed in, or credentials have expired, InnerTube may return a sign
+/// in prompt instead of the home feed.
+/// Other responses without a home feed are left to fail as a parse error.
+fn is_sign_in_required(json_crawler: &JsonCrawlerOwned) -> bool {
+    json_crawler.path_exists(concatcp!(SINGLE_COLUMN_TAB, "/musicSignInPromptRenderer"))
+        || json_crawler.path_exists(concatcp!(SINGLE_COLUMN_TAB, "/messageRenderer/button"))
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, err(Display), fields(sections, skipped))
+)]
+fn parse_home_contents(
+    mut section_list: JsonCrawlerOwned,
+    options: &HomeParseOptions,
+) -> Result<(HomeSections, Option<ContinuationParams<'static>>)> {
+    let continuation_params = section_list.take_value_pointer(CONTINUATION_PARAMS).ok();
+    // Chip cloud is not present when the feed is empty.
+    let (chips, more_moods_params) = match section_list.borrow_pointer(CHIP_CLOUD_CHIPS) {
+        Ok(chips) if options.parse_chips => parse_chip_cloud(chips)?,
+        _ => (Vec::new(), None),
+    };
+    let mut warnings = Vec::new();
+    let mut unknown_renderers = Vec::new();
+    let sections = parse_mixed_content(
+        section_list.borrow_pointer("/contents")?,
+        &mut warnings,
+        &mut unknown_renderers,
+        options,
+    )?;
+    let home = HomeSections {
+        more_moods_params,
+        warnings,
+        unknown_renderers,
+        ..HomeSections::new(chips, sections)
+    };
+    #[cfg(feature = "tracing")]
+    record_home_counts(&home);
+    Ok((home, continuation_params))
+}
+
+/// Parse the mood chips, and the params of the trailing 'More' chip if there
+/// is one.
+fn parse_chip_cloud(
+    chips: JsonCrawlerBorrowed,
+) -> Result<(Vec<HomeMoodChip>, Option<MoodCategoryParams<'static>>)> {
+    let mut parsed = Vec::new();
+    let mut more_moods_params = None;
+    for item in chips.try_into_iter()? {
+        let mut chip = item.navigate_pointer("/chipCloudChipRenderer")?;
+        let is_more_chip = chip
+            .borrow_value_pointer::<String>(NAVIGATION_BROWSE_ID)
+            .is_ok_and(|browse_id| browse_id == MOODS_AND_GENRES_BROWSE_ID);
+        if is_more_chip {
+            more_moods_params = chip
+                .take_value_pointer(concatcp!(NAVIGATION_BROWSE, "/params"))
+                .ok();
+            continue;
+        }
+        let title = take_text(&mut chip, TEXT_RUN_TEXT)?;
+        let params = chip.take_value_pointer(concatcp!(NAVIGATION_BROWSE, "/params"))?;
+        // Fall back to the chip style if the selected flag is missing.
+        let selected = match chip.take_value_pointer::<bool>("/isSelected") {
+            Ok(selected) => selected,
+            Err(_) => chip
+                .take_value_pointer::<String>("/style/styleType")
+                .is_ok_and(|style| style == "STYLE_PRIMARY"),
+        };
+        parsed.push(HomeMoodChip {
+            title,
+            params,
+            selected,
+        });
+    }
+    Ok((parsed, more_moods_params))
+}
+
+/// Record the number of parsed sections and skipped rows on the current span.
+#[cfg(feature = "tracing")]
+fn record_home_counts(home: &HomeSections) {
+    let span = tracing::Span::current();
+    span.record("sections", home.sections.len());
+    span.record("skipped", home.warnings.len());
+}
+
+/// Iterate over the rows of `array`, parsing each using `f`. Rows where `f`
+/// returns an error are skipped, and the error is pushed to `warnings`
+/// instead. Rows where `f` returns Ok(None) are skipped silently.
+/// If `limit` is set, iteration stops once that many rows have been parsed, and
+/// the remaining rows are not visited.
+fn try_iter_with_warnings<T>(
+    mut array: JsonCrawlerBorrowed,
+    warnings: &mut Vec<ParseWarning>,
+    limit: Option<usize>,
+    mut f: impl FnMut(JsonCrawlerBorrowed, &mut Vec<ParseWarning>) -> Result<Option<T>>,
+) -> Result<Vec<T>> {
+    let rows = array.try_iter_mut()?;
+    let limit = limit.unwrap_or(usize::MAX);
+    let mut parsed = Vec::with_capacity(rows.len().min(limit));
+    for row in rows {
+        if parsed.len() >= limit {
+            break;
+        }
+        match f(row, warnings) {
+            Ok(Some(item)) => parsed.push(item),
+            Ok(None) => (),
+            Err(e) => {
+                // Error message includes the path at which parsing failed.
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %e, "Skipped malformed home feed row");
+                warnings.push(ParseWarning {
+                    message: e.to_string(),
+                })
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+fn parse_mixed_content(
+    contents: JsonCrawlerBorrowed,
+    warnings: &mut Vec<ParseWarning>,
+    unknown_renderers: &mut Vec<String>,
+    options: &HomeParseOptions,
+) -> Result<Vec<HomeSection>> {
+    let mut sections = try_iter_with_warnings(contents, warnings, None, |mut row, warnings| {
+        // The featured shelf at the top of the feed is an immersive carousel, but
+        // otherwise has the same structure.
+        let path = if row.path_exists(CAROUSEL) {
+            CAROUSEL
+        } else if row.path_exists(IMMERSIVE_CAROUSEL) {
+            IMMERSIVE_CAROUSEL
+        } else {
+            // Other shelf types are not currently supported, and are skipped.
+            if options.audit_renderers {
+                record_renderer_names(&row, unknown_renderers);
+            }
+            return Ok(None);
+        };
+        parse_carousel_section(
+            row.borrow_pointer(path)?,
+            warnings,
+            unknown_renderers,
+            options,
+        )
+        .map(Some)
+    })?;
+    for (position, section) in sections.iter_mut().enumerate() {
+        section.position = position;
+    }
+    Ok(sections)
+}
+
+/// Record the top level keys of `json`, i.e the renderer names, if not already
+/// recorded.
+fn record_renderer_names(json: &impl JsonCrawler, unknown_renderers: &mut Vec<String>) {
+    let Ok(renderers) = json.borrow_value::<BTreeMap<String, IgnoredAny>>() else {
+        return;
+    };
+    for name in renderers.into_keys() {
+        if !unknown_renderers.contains(&name) {
+            unknown_renderers.push(name);
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, err(Display), fields(title, items))
+)]
+fn parse_carousel_section(
+    mut carousel: JsonCrawlerBorrowed,
+    warnings: &mut Vec<ParseWarning>,
+    unknown_renderers: &mut Vec<String>,
+    options: &HomeParseOptions,
+) -> Result<HomeSection> {
+    let header_path = if carousel.path_exists(IMMERSIVE_CAROUSEL_HEADER) {
+        IMMERSIVE_CAROUSEL_HEADER
+    } else {
+        CAROUSEL_HEADER
+    };
+    let mut header = carousel.borrow_pointer(header_path)?;
+    let title = take_text(&mut header, TITLE_TEXT)?;
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("title", title.as_str());
+    // Straplines can span several runs, e.g to format a name.
+    let strapline = join_runs(&mut header, "/strapline/runs");
+    let thumbnails = header
+        .take_value_pointer("/thumbnail/musicThumbnailRenderer/thumbnail/thumbnails")
+        .unwrap_or_default();
+    let animated_thumbnail = header.take_value_pointer(ANIMATED_THUMBNAIL_URL).ok();
+    let more_browse_id: Option<String> = header
+        .take_value_pointer(concatcp!(
+            "/moreContentButton/buttonRenderer",
+            NAVIGATION_BROWSE_ID
+        ))
+        .ok();
+    let more_params = header
+        .take_value_pointer(concatcp!(
+            "/moreContentButton/buttonRenderer",
+            NAVIGATION_BROWSE,
+            "/params"
+        ))
+        .ok();
+    let more_label = header
+        .take_value_pointer("/moreContentButton/buttonRenderer/text/runs/0/text")
+        .ok();
+    let shuffle_params = header
+        .take_value_pointer(
+            "/shuffleButton/buttonRenderer/navigationEndpoint/watchPlaylistEndpoint/params",
+        )
+        .ok();
+    let tracking_params = carousel.take_value_pointer("/trackingParams").ok();
+    let mut contents = try_iter_with_warnings(
+        carousel.borrow_pointer("/contents")?,
+        warnings,
+        options.max_items_per_section,
+        |item, _| {
+            let mut content = parse_home_item(item, unknown_renderers, options)?;
+            if options.smallest_thumbnail_only
+                && let Some(content) = &mut content
+            {
+                content.retain_smallest_thumbnail();
+            }
+            Ok(content)
+        },
+    )?;
+    if is_library_shelf(&title, more_browse_id.as_deref()) {
+        contents.iter_mut().for_each(HomeContent::mark_from_library);
+    }
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("items", contents.len());
+    Ok(HomeSection {
+        title,
+        strapline,
+        thumbnails,
+        animated_thumbnail,
+        more_params,
+        more_label,
+        shuffle_params,
+        tracking_params,
+        // Set once all the sections in the page have been parsed.
+        position: 0,
+        contents,
+    })
+}
+
+/// A shelf is from the user's library if its 'More' button links to the
+/// library, falling back to the title if it has no 'More' button.
+fn is_library_shelf(title: &str, more_browse_id: Option<&str>) -> bool {
+    match more_browse_id {
+        Some(id) => id.starts_with("FEmusic_library") || id.starts_with("FEmusic_liked"),
+        None => {
+            let title = title.to_lowercase();
+            LIBRARY_TOKENS.iter().any(|t| title.contains(t))
+        }
+    }
+}
+
+/// Locations of the fields that differ between the renderers used for cards.
+/// The two column renderer links from the card itself instead of its title,
+/// and doesn't wrap its thumbnail in a `thumbnailRenderer`.
+struct CardLayout {
+    browse_id: &'static str,
+    page_type: &'static str,
+    thumbnails: &'static str,
+}
+
+const TWO_ROW_CARD: CardLayout = CardLayout {
+    browse_id: concatcp!(TITLE, NAVIGATION_BROWSE_ID),
+    page_type: concatcp!(TITLE, NAVIGATION_BROWSE, PAGE_TYPE),
+    thumbnails: THUMBNAIL_RENDERER,
+};
+
+const TWO_COLUMN_CARD: CardLayout = CardLayout {
+    browse_id: NAVIGATION_BROWSE_ID,
+    page_type: concatcp!(NAVIGATION_BROWSE, PAGE_TYPE),
+    thumbnails: THUMBNAILS,
+};
+
+/// Returns Ok(None) if the item is of a type that is not currently supported.
+fn parse_home_item(
+    item: JsonCrawlerBorrowed,
+    unknown_renderers: &mut Vec<String>,
+    options: &HomeParseOptions,
+) -> Result<Option<HomeContent>> {
+    // Quick picks shelf uses list items instead of cards.
+    if item.path_exists(MRLIR) {
+        return parse_home_list_item(item.navigate_pointer(MRLIR)?, options)
+            .map_err(|e| e.with_context("Unable to parse home list item"));
+    }
+    let (data, layout) = if item.path_exists(MTRIR) {
+        (item.navigate_pointer(MTRIR)?, &TWO_ROW_CARD)
+    } else if item.path_exists(MTCIR) {
+        (item.navigate_pointer(MTCIR)?, &TWO_COLUMN_CARD)
+    } else {
+        if options.audit_renderers {
+            record_renderer_names(&item, unknown_renderers);
+        }
+        return Ok(None);
+    };
+    if data.path_exists(NAVIGATION_WATCH_PLAYLIST_ID) || is_mix_browse_card(&data, layout) {
+        return Ok(Some(HomeContent::WatchPlaylist(
+            parse_home_watch_playlist(data, layout, options)
+                .map_err(item_context(HomeContentKind::WatchPlaylist))?,
+        )));
+    }
+    let page_type: Option<String> = data.borrow_value_pointer(layout.page_type).ok();
+    let content = match page_type.as_deref() {
+        // Songs and videos link directly to the watch page.
+        None => {
+            let video_type = data
+                .borrow_value_pointer(concatcp!("/navigationEndpoint", NAVIGATION_VIDEO_TYPE))
+                .ok();
+            match video_type {
+                Some(
+                    YoutubeMusicVideoType::Ugc
+                    | YoutubeMusicVideoType::Omv
+                    | YoutubeMusicVideoType::Shoulder
+                    | YoutubeMusicVideoType::OfficialSourceMusic,
+                ) => HomeContent::Video(
+                    parse_home_video(data, layout, options)
+                        .map_err(item_context(HomeContentKind::Video))?,
+                ),
+                Some(YoutubeMusicVideoType::Atv) | None => HomeContent::Song(
+                    parse_home_song(data, layout, options)
+                        .map_err(item_context(HomeContentKind::Song))?,
+                ),
+                Some(YoutubeMusicVideoType::Episode | YoutubeMusicVideoType::Upload) => {
+                    return Ok(None);
+                }
+            }
+        }
+        // Some song cards link to a track page instead, but still contain a watch
+        // endpoint.
+        Some("MUSIC_PAGE_TYPE_TRACK") => HomeContent::Song(
+            parse_home_song(data, layout, options).map_err(item_context(HomeContentKind::Song))?,
+        ),
+        Some("MUSIC_PAGE_TYPE_ALBUM" | "MUSIC_PAGE_TYPE_AUDIOBOOK") => HomeContent::Album(
+            parse_home_album(data, layout, options)
+                .map_err(item_context(HomeContentKind::Album))?,
+        ),
+        Some("MUSIC_PAGE_TYPE_PLAYLIST") => HomeContent::Playlist(
+            parse_home_playlist(data, layout, options)
+                .map_err(item_context(HomeContentKind::Playlist))?,
+        ),
+        Some("MUSIC_PAGE_TYPE_ARTIST" | "MUSIC_PAGE_TYPE_USER_CHANNEL") => HomeContent::Artist(
+            parse_home_artist(data, layout, options)
+                .map_err(item_context(HomeContentKind::Artist))?,
+        ),
+        Some("MUSIC_PAGE_TYPE_PODCAST_SHOW_DETAIL_PAGE") => HomeContent::Podcast(
+            parse_home_podcast(data, layout, options)
+                .map_err(item_context(HomeContentKind::Podcast))?,
+        ),
+        Some(_) => return Ok(None),
+    };
+    Ok(Some(content))
+}
+
+/// Add the kind of item being parsed to an error, as the error itself only
+/// includes the JSON pointer that failed.
+fn item_context(kind: HomeContentKind) -> impl FnOnce(Error) -> Error {
+    move |e| e.with_context(format!("Unable to parse home {kind:?}"))
+}
+
+fn parse_home_song(
+    mut data: JsonCrawlerBorrowed,
+    layout: &CardLayout,
+    options: &HomeParseOptions,
+) -> Result<HomeSong> {
+    let subtitle = parse_subtitle(&mut data, SUBTITLE_RUNS, options);
+    // Album must be parsed first, as it relies on the artist links.
+    let album = parse_album_from_runs(&mut data, SUBTITLE_RUNS)?;
+    let artists = parse_artists_from_runs(&mut data, SUBTITLE_RUNS)?;
+    let badges = parse_badges(&data, "/subtitleBadges");
+    let explicit = parse_explicit(badges.as_deref());
+    let is_premium_only = parse_premium_only(badges.as_deref());
+    let duration = data
+        .borrow_pointer(SUBTITLE_RUNS)
+        .ok()
+        .and_then(|runs| runs.try_into_iter().ok())
+        .and_then(|mut runs| {
+            runs.find_map(|run| {
+                run.borrow_value_pointer::<String>("/text")
+                    .ok()
+                    .filter(|text| parse_duration_seconds(text).is_some())
+            })
+        });
+    Ok(HomeSong {
+        title: take_text(&mut data, TITLE_TEXT)?,
+        video_id: data.take_value_pointer(NAVIGATION_VIDEO_ID)?,
+        artists,
+        album,
+        explicit,
+        is_premium_only,
+        duration_seconds: duration.as_deref().and_then(parse_duration_seconds),
+        duration,
+        library_management: parse_home_library_management(&mut data)?,
+        like_feedback_tokens: parse_home_like_feedback_tokens(&mut data),
+        thumbnails: data
+            .take_value_pointer(layout.thumbnails)
+            .unwrap_or_default(),
+        subtitle,
+        from_library: false,
+    })
+}
+
+/// Returns Ok(None) if the item is not playable.
+fn parse_home_list_item(
+    mut data: JsonCrawlerBorrowed,
+    options: &HomeParseOptions,
+) -> Result<Option<HomeContent>> {
+    let Ok(video_id) = data.take_value_pointers(&[
+        concatcp!(PLAY_BUTTON, "/playNavigationEndpoint", WATCH_VIDEO_ID),
+        "/playlistItemData/videoId",
+    ]) else {
+        return Ok(None);
+    };
+    let title = collapse_whitespace(&parse_flex_column_item::<String>(&mut data, 0, 0)?);
+    let details_runs = format!("{}/text/runs", flex_column_item_pointer(1));
+    let subtitle = parse_subtitle(&mut data, &details_runs, options);
+    let album = parse_album_from_runs(&mut data, &details_runs)?;
+    let artists = parse_artists_from_runs(&mut data, &details_runs)?;
+    let badges = parse_badges(&data, "/badges");
+    let explicit = parse_explicit(badges.as_deref());
+    let is_premium_only = parse_premium_only(badges.as_deref());
+    let duration: Option<String> = data
+        .borrow_pointer(fixed_column_item_pointer(0))
+        .and_then(|mut i| i.take_value_pointers(&["/text/simpleText", "/text/runs/0/text"]))
+        .ok();
+    Ok(Some(HomeContent::Song(HomeSong {
+        title,
+        video_id,
+        artists,
+        album,
+        explicit,
+        is_premium_only,
+        duration_seconds: duration.as_deref().and_then(parse_duration_seconds),
+        duration,
+        library_management: parse_home_library_management(&mut data)?,
+        like_feedback_tokens: parse_home_like_feedback_tokens(&mut data),
+        thumbnails: data.take_value_pointer(THUMBNAILS).unwrap_or_default(),
+        subtitle,
+        from_library: false,
+    })))
+}
+
+fn parse_home_video(
+    mut data: JsonCrawlerBorrowed,
+    layout: &CardLayout,
+    options: &HomeParseOptions,
+) -> Result<HomeVideo> {
+    let subtitle = parse_subtitle(&mut data, SUBTITLE_RUNS, options);
+    let artists = parse_artists_from_runs(&mut data, SUBTITLE_RUNS)?;
+    let views = find_subtitle_run(&mut data, &options.lexicon.views).and_then(metric_count);
+    let badges = parse_badges(&data, "/subtitleBadges");
+    let explicit = parse_explicit(badges.as_deref());
+    let is_premium_only = parse_premium_only(badges.as_deref());
+    Ok(HomeVideo {
+        title: take_text(&mut data, TITLE_TEXT)?,
+        video_id: data.take_value_pointer(NAVIGATION_VIDEO_ID)?,
+        artists,
+        explicit,
+        is_premium_only,
+        views,
+        thumbnails: data
+            .take_value_pointer(layout.thumbnails)
+            .unwrap_or_default(),
+        subtitle,
+        from_library: false,
+    })
+}
+
+fn parse_home_album(
+    mut data: JsonCrawlerBorrowed,
+    layout: &CardLayout,
+    options: &HomeParseOptions,
+) -> Result<HomeAlbum> {
+    let subtitle = parse_subtitle(&mut data, SUBTITLE_RUNS, options);
+    let artists = parse_artists_from_runs(&mut data, SUBTITLE_RUNS)?;
+    let album_type = data.borrow_value_pointer(SUBTITLE).ok();
+    // The year isn't always at the same position, and an artist name may also
+    // look like a year, so the last four digit run is taken.
+    let year = data
+        .borrow_pointer(SUBTITLE_RUNS)
+        .ok()
+        .and_then(|runs| runs.try_into_iter().ok())
+        .and_then(|runs| {
+            runs.filter_map(|run| run.borrow_value_pointer::<String>("/text").ok())
+                .filter(|text| is_year(text))
+                .last()
+        });
+    let track_count = parse_track_count(&mut data);
+    let badges = parse_badges(&data, "/subtitleBadges");
+    let explicit = parse_explicit(badges.as_deref());
+    let is_premium_only = parse_premium_only(badges.as_deref());
+    let (play_params, play_video_id) = parse_play_endpoint(&mut data);
+    Ok(HomeAlbum {
+        title: take_text(&mut data, TITLE_TEXT)?,
+        album_id: data.take_value_pointer(layout.browse_id)?,
+        album_type,
+        artists,
+        year,
+        track_count,
+        explicit,
+        is_premium_only,
+        thumbnails: data
+            .take_value_pointer(layout.thumbnails)
+            .unwrap_or_default(),
+        subtitle,
+        play_params,
+        play_video_id,
+        from_library: false,
+    })
+}
+
+fn parse_home_playlist(
+    mut data: JsonCrawlerBorrowed,
+    layout: &CardLayout,
+    options: &HomeParseOptions,
+) -> Result<HomePlaylist> {
+    let subtitle = parse_subtitle(&mut data, SUBTITLE_RUNS, options);
+    let author = parse_artists_from_runs(&mut data, SUBTITLE_RUNS)?;
+    let author_kind = author
+        .first()
+        .and_then(|author| match author.channel_kind() {
+            ChannelKind::Artist | ChannelKind::Topic => Some(HomeAuthorKind::Artist),
+            ChannelKind::UserChannel => Some(HomeAuthorKind::UserChannel),
+            _ => None,
+        });
+    let browse_id: String = data.take_value_pointer(layout.browse_id)?;
+    // Browse id for a playlist is the playlist id prefixed with 'VL'.
+    let playlist_id = PlaylistID::from_raw(
+        browse_id
+            .strip_prefix("VL")
+            .unwrap_or(&browse_id)
+            .to_string(),
+    );
+    let (play_params, play_video_id) = parse_play_endpoint(&mut data);
+    Ok(HomePlaylist {
+        title: take_text(&mut data, TITLE_TEXT)?,
+        playlist_id,
+        browse_id,
+        author,
+        author_kind,
+        thumbnails: data
+            .take_value_pointer(layout.thumbnails)
+            .unwrap_or_default(),
+        subtitle,
+        play_params,
+        play_video_id,
+        from_library: false,
+    })
+}
+
+fn parse_home_artist(
+    mut data: JsonCrawlerBorrowed,
+    layout: &CardLayout,
+    options: &HomeParseOptions,
+) -> Result<HomeArtist> {
+    let subtitle = parse_subtitle(&mut data, SUBTITLE_RUNS, options);
+    // The subscriber count isn't always the leading run, e.g 'Artist • 1.2M
+    // subscribers'.
+    let subscribers =
+        find_subtitle_run(&mut data, &options.lexicon.subscribers).and_then(metric_count);
+    let (subscribe_params, unsubscribe_params) = parse_subscribe_button(&mut data);
+    Ok(HomeArtist {
+        title: take_text(&mut data, TITLE_TEXT)?,
+        channel_id: data.take_value_pointer(layout.browse_id)?,
+        subscribers,
+        subscribe_params,
+        unsubscribe_params,
+        thumbnails: data
+            .take_value_pointer(layout.thumbnails)
+            .unwrap_or_default(),
+        subtitle,
+        from_library: false,
+    })
+}
+
+fn parse_home_podcast(
+    mut data: JsonCrawlerBorrowed,
+    layout: &CardLayout,
+    options: &HomeParseOptions,
+) -> Result<HomePodcast> {
+    let subtitle = parse_subtitle(&mut data, SUBTITLE_RUNS, options);
+    let publisher = parse_publisher_from_runs(&mut data, SUBTITLE_RUNS)?;
+    let episode_count = parse_episode_count(&mut data);
+    Ok(HomePodcast {
+        title: take_text(&mut data, TITLE_TEXT)?,
+        podcast_id: data.take_value_pointer(layout.browse_id)?,
+        publisher,
+        episode_count,
+        thumbnails: data
+            .take_value_pointer(layout.thumbnails)
+            .unwrap_or_default(),
+        subtitle,
+        from_library: false,
+    })
+}
+
+/// Get the params of the subscribe and unsubscribe endpoints of the card's
+/// subscribe button. Unsubscribing may be behind a confirmation popup.
+fn parse_subscribe_button(data: &mut impl JsonCrawler) -> (Option<String>, Option<String>) {
+    let Ok(mut endpoints) = data.borrow_pointer(SUBSCRIBE_BUTTON_ENDPOINTS) else {
+        return (None, None);
+    };
+    let Ok(endpoints) = endpoints.try_iter_mut() else {
+        return (None, None);
+    };
+    let mut subscribe_params = None;
+    let mut unsubscribe_params = None;
+    for mut endpoint in endpoints {
+        if subscribe_params.is_none() {
+            subscribe_params = endpoint
+                .take_value_pointer("/subscribeEndpoint/params")
+                .ok();
+        }
+        if unsubscribe_params.is_none() {
+            unsubscribe_params = endpoint
+                .take_value_pointers(&[
+                    "/unsubscribeEndpoint/params",
+                    concatcp!(
+                        "/signalServiceEndpoint/actions/0/openPopupAction/popup",
+                        "/confirmDialogRenderer/confirmButton/buttonRenderer",
+                        "/serviceEndpoint/unsubscribeEndpoint/params"
+                    ),
+                ])
+                .ok();
+        }
+    }
+    (subscribe_params, unsubscribe_params)
+}
+
+fn parse_home_watch_playlist(
+    mut data: JsonCrawlerBorrowed,
+    layout: &CardLayout,
+    options: &HomeParseOptions,
+) -> Result<HomeWatchPlaylist> {
+    let playlist_id = match data.take_value_pointer(NAVIGATION_WATCH_PLAYLIST_ID) {
+        Ok(playlist_id) => playlist_id,
+        // Mix cards that only link to the playlist's browse page.
+        Err(_) => {
+            let browse_id: String = data.take_value_pointer(layout.browse_id)?;
+            PlaylistID::from_raw(
+                browse_id
+                    .strip_prefix("VL")
+                    .unwrap_or(&browse_id)
+                    .to_string(),
+            )
+        }
+    };
+    Ok(HomeWatchPlaylist {
+        title: take_text(&mut data, TITLE_TEXT)?,
+        playlist_id,
+        thumbnails: parse_montage_thumbnails(&mut data, layout)?,
+        subtitle: parse_subtitle(&mut data, SUBTITLE_RUNS, options),
+        tracking_params: data.take_value_pointer("/trackingParams").ok(),
+        from_library: false,
+    })
+}
+
+/// Some mix cards link to the browse page of the mix playlist, instead of
+/// directly to a watch playlist.
+fn is_mix_browse_card(data: &impl JsonCrawler, layout: &CardLayout) -> bool {
+    let is_playlist = data
+        .borrow_value_pointer::<String>(layout.page_type)
+        .is_ok_and(|page_type| page_type == "MUSIC_PAGE_TYPE_PLAYLIST");
+    is_playlist
+        && data
+            .borrow_value_pointer::<String>(layout.browse_id)
+            .is_ok_and(|browse_id| {
+                browse_id.strip_prefix("VL").is_some_and(|playlist_id| {
+                    playlist_id.starts_with(MIX_PLAYLIST_PREFIX)
+                        && !playlist_id.starts_with(CURATED_PLAYLIST_PREFIX)
+                })
+            })
+}
+
+/// Get the params and video id of the card's play button, if it has one.
+/// Depending on the card the button may link to either a watch or a watch
+/// playlist endpoint.
+fn parse_play_endpoint(data: &mut impl JsonCrawler) -> (Option<String>, Option<VideoID<'static>>) {
+    let Ok(mut endpoint) = data.borrow_pointer(THUMBNAIL_OVERLAY_PLAY) else {
+        return (None, None);
+    };
+    let params = endpoint
+        .take_value_pointer("/watchEndpoint/params")
+        .or_else(|_| endpoint.take_value_pointer("/watchPlaylistEndpoint/params"))
+        .ok();
+    let video_id = endpoint.take_value_pointer(WATCH_VIDEO_ID).ok();
+    (params, video_id)
+}
+
+/// Some mixes display a collage of several thumbnails - in this case all tiles
+/// are returned, in order.
+fn parse_montage_thumbnails(
+    data: &mut impl JsonCrawler,
+    layout: &CardLayout,
+) -> Result<Vec<Thumbnail>> {
+    let Ok(tiles) = data.borrow_pointer(THUMBNAIL_RENDERER_MONTAGE) else {
+        return Ok(data
+            .take_value_pointer(layout.thumbnails)
+            .unwrap_or_default());
+    };
+    let tiles = tiles
+        .try_into_iter()?
+        .map(|mut tile| {
+            tile.take_value_pointer::<Vec<Thumbnail>>(concatcp!(
+                "/musicThumbnailRenderer",
+                THUMBNAIL
+            ))
+        })
+        .collect::<CrawlerResult<Vec<_>>>()?;
+    Ok(tiles.into_iter().flatten().collect())
+}
+
+/// The full subtitle, unless disabled by
+/// [`HomeParseOptions::keep_raw_subtitle`].
+fn parse_subtitle(
+    data: &mut impl JsonCrawler,
+    runs: &str,
+    options: &HomeParseOptions,
+) -> Option<String> {
+    if !options.keep_raw_subtitle {
+        return None;
+    }
+    join_runs(data, runs)
+}
+
+/// Join the text of all runs at `runs`, as displayed on the card.
+fn join_runs(data: &mut impl JsonCrawler, runs: &str) -> Option<String> {
+    let parts = data
+        .borrow_pointer(runs)
+        .ok()?
+        .try_into_iter()
+        .ok()?
+        .map(|run| run.borrow_value_pointer::<String>("/text"))
+        .collect::<CrawlerResult<Vec<_>>>()
+        .ok()?;
+    Some(collapse_whitespace(&parts.join("")))
+}
+
+/// Take the string at `pointer`, with whitespace collapsed.
+fn take_text(data: &mut impl JsonCrawler, pointer: &str) -> CrawlerResult<String> {
+    data.take_value_pointer::<String>(pointer)
+        .map(|text| collapse_whitespace(&text))
+}
+
+/// Trim `text` and collapse internal runs of whitespace into a single space,
+/// since joining runs can leave doubled spaces.
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Cards without a menu have no library management tokens.
+fn parse_home_library_management(data: &mut impl JsonCrawler) -> Result<Option<LibraryManager>> {
+    match data.borrow_pointer(MENU_ITEMS) {
+        Ok(menu) => parse_library_management_items_from_menu(menu),
+        Err(_) => Ok(None),
+    }
+}
+
+/// Get the like status and rating params from the like button in the card's
+/// menu. Cards without a menu or like button have no like feedback tokens.
+fn parse_home_like_feedback_tokens(data: &mut impl JsonCrawler) -> Option<LikeFeedbackTokens> {
+    let mut buttons = data.borrow_pointer(MENU_TOP_LEVEL_BUTTONS).ok()?;
+    let mut button = buttons
+        .try_iter_mut()
+        .ok()?
+        .find_map(|button| button.navigate_pointer("/likeButtonRenderer").ok())?;
+    let mut tokens = LikeFeedbackTokens {
+        status: button
+            .take_value_pointer("/likeStatus")
+            .unwrap_or(LikeStatus::Indifferent),
+        like_params: None,
+        dislike_params: None,
+        indifferent_params: None,
+    };
+    let Ok(endpoints) = button.borrow_pointer("/serviceEndpoints") else {
+        return Some(tokens);
+    };
+    let Ok(endpoints) = endpoints.try_into_iter() else {
+        return Some(tokens);
+    };
+    for mut endpoint in endpoints {
+        let Ok(status) = endpoint.take_value_pointer("/likeEndpoint/status") else {
+            continue;
+        };
+        let params = endpoint.take_value_pointer("/likeEndpoint/params").ok();
+        match status {
+            LikeStatus::Liked => tokens.like_params = params,
+            LikeStatus::Disliked => tokens.dislike_params = params,
+            LikeStatus::Indifferent => tokens.indifferent_params = params,
+        }
+    }
+    Some(tokens)
+}
+
+/// A badge displayed next to the title or subtitle of an item.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum Badge {
+    Explicit,
+    /// Only available to YouTube Music Premium subscribers.
+    Premium,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawBadge {
+    music_inline_badge_renderer: Option<RawInlineBadge>,
+}
+
+#[derive(Deserialize)]
+struct RawInlineBadge {
+    icon: Option<RawBadgeIcon>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawBadgeIcon {
+    icon_type: String,
+}
+
+/// Parse the array of badges at `badges`, so that it's only read once per item.
+/// Badges that aren't recognised are skipped. Returns None if `badges` doesn't
+/// exist.
+fn parse_badges(data: &impl JsonCrawler, badges: &str) -> Option<Vec<Badge>> {
+    if !data.path_exists(badges) {
+        return None;
+    }
+    let raw = data
+        .borrow_value_pointer::<Vec<RawBadge>>(badges)
+        .unwrap_or_default();
+    Some(
+        raw.into_iter()
+            .filter_map(
+                |badge| match badge.music_inline_badge_renderer?.icon?.icon_type.as_str() {
+                    "MUSIC_EXPLICIT_BADGE" => Some(Badge::Explicit),
+                    "MUSIC_PREMIUM" => Some(Badge::Premium),
+                    _ => None,
+                },
+            )
+            .collect(),
+    )
+}
+
+/// The badge is only present on explicit items, so an item is only known to be
+/// clean if it has other badges. If it has no badges the status is unknown.
+fn parse_explicit(badges: Option<&[Badge]>) -> Option<Explicit> {
+    badges.map(|badges| {
+        if badges.contains(&Badge::Explicit) {
+            Explicit::IsExplicit
+        } else {
+            Explicit::NotExplicit
+        }
+    })
+}
+
+/// An item is gated to Premium subscribers if it has a Premium badge.
+fn parse_premium_only(badges: Option<&[Badge]>) -> bool {
+    badges.is_some_and(|badges| badges.contains(&Badge::Premium))
+}
+
+/// Parse a duration in the form "m:ss" or "h:mm:ss" into seconds.
+fn parse_duration_seconds(duration: &str) -> Option<u32> {
+    let parts = duration.trim().split(':').collect::<Vec<_>>();
+    let (first, rest) = parts.split_first()?;
+    if rest.is_empty() || rest.len() > 2 {
+        return None;
+    }
+    let mut seconds: u32 = first.parse().ok()?;
+    for part in rest {
+        let value: u32 = part.parse().ok().filter(|v| *v < 60 && part.len() == 2)?;
+        seconds = seconds.checked_mul(60)?.checked_add(value)?;
+    }
+    Some(seconds)
+}
+
+/// Lowercase and strip accents from common latin characters, for loose
+/// comparison of localised titles.
+fn normalise_title(title: &str) -> String {
+    title
+        .trim()
+        .to_lowercase()
+        .chars()
+        // Combining diacritical marks, for titles that are already decomposed.
+        .filter(|c| !('\u{0300}'..='\u{036f}').contains(c))
+        .map(strip_accent)
+        .collect()
+}
+
+fn strip_accent(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'ł' | 'ľ' | 'ĺ' | 'ļ' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ř' => 'r',
+        'ś' | 'ş' | 'š' | 'ș' => 's',
+        'ţ' | 'ť' | 'ț' => 't',
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        c => c,
+    }
+}
+
+/// Get the text of the first subtitle run containing one of `tokens`.
+fn find_subtitle_run(data: &mut impl JsonCrawler, tokens: &[String]) -> Option<String> {
+    data.borrow_pointer(SUBTITLE_RUNS)
+        .ok()?
+        .try_into_iter()
+        .ok()?
+        .filter_map(|run| run.borrow_value_pointer::<String>("/text").ok())
+        .find(|text| tokens.iter().any(|t| text.contains(t.as_str())))
+}
+
+/// Get the track count from a subtitle run such as '12 songs'.
+fn parse_track_count(data: &mut impl JsonCrawler) -> Option<u32> {
+    data.borrow_pointer(SUBTITLE_RUNS)
+        .ok()?
+        .try_into_iter()
+        .ok()?
+        .filter_map(|run| run.borrow_value_pointer::<String>("/text").ok())
+        .find_map(|text| {
+            let text = text.to_lowercase();
+            let count = parse_localized_number(&text)
+                .filter(|count| count.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(count))?;
+            text.split_whitespace()
+                .any(|word| TRACK_COUNT_TOKENS.iter().any(|t| word.starts_with(t)))
+                .then_some(count as u32)
+        })
+}
+
+/// Get the episode count of a podcast, from the first subtitle run that is a
+/// whole number, e.g '120 episodes'. Runs linking to a channel are skipped, as
+/// a publisher name may start with a number.
+fn parse_episode_count(data: &mut impl JsonCrawler) -> Option<u32> {
+    data.borrow_pointer(SUBTITLE_RUNS)
+        .ok()?
+        .try_into_iter()
+        .ok()?
+        .filter(|run| !run.path_exists(NAVIGATION_BROWSE_ID))
+        .filter_map(|run| run.borrow_value_pointer::<String>("/text").ok())
+        .find_map(|text| {
+            parse_localized_number(&text)
+                .filter(|count| count.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(count))
+                .map(|count| count as u32)
+        })
+}
+
+/// Get the count from a metric run, e.g '1.2M' from '1.2M views' or '1,2 M'
+/// from '1,2 M de vues'. This is the first number in the run, as some locales
+/// place the count after the label.
+fn metric_count(run: String) -> Option<String> {
+    split_localized_number(&run).map(|(count, _)| count.to_string())
+}
+
+/// True if the whole of `text` is a whole number in a plausible range for the
+/// release year of an album. Abbreviated numbers such as '2K' aren't years.
+fn is_year(text: &str) -> bool {
+    split_localized_number(text).is_some_and(|(matched, number)| {
+        matched == text.trim()
+            && !matched.chars().any(char::is_alphabetic)
+            && number.fract() == 0.0
+            && (1900.0..=2100.0).contains(&number)
+    })
+}
+
+/// Parse the first number in `text`, as formatted for display in any of the
+/// supported locales, e.g '1,234', '1.234', '12,34,567', '1.2M' or '120万'.
+/// As the locale isn't known, a single separator followed by three digits is
+/// assumed to group thousands unless the number is abbreviated.
+fn parse_localized_number(text: &str) -> Option<f64> {
+    split_localized_number(text).map(|(_, number)| number)
+}
+
+/// As for [`parse_localized_number`], but also returns the number as it
+/// appears in `text`, including any abbreviation.
+fn split_localized_number(text: &str) -> Option<(&str, f64)> {
+    const SEPARATORS: [char; 6] = [',', '.', ' ', '\'', '\u{a0}', '\u{202f}'];
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let rest = &text[start..];
+    // Separators are only part of the number if followed by a digit.
+    let mut end = 0;
+    let mut chars = rest.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let next_is_digit = chars.peek().is_some_and(|(_, c)| c.is_ascii_digit());
+        if c.is_ascii_digit() || (SEPARATORS.contains(&c) && next_is_digit) {
+            end = i + c.len_utf8();
+        } else {
+            break;
+        }
+    }
+    let digits = &rest[..end];
+    // The abbreviation may be separated from the number by a space.
+    let after = rest[end..].trim_start();
+    let suffix_start = rest.len() - after.len();
+    let suffix_word = after.split_whitespace().next().unwrap_or_default();
+    let suffix = NUMBER_SUFFIXES.iter().find(|(suffix, _)| {
+        // CJK abbreviations aren't followed by a space.
+        suffix_word == *suffix || (!suffix.is_ascii() && suffix_word.starts_with(suffix))
+    });
+    let (matched_len, multiplier) = match suffix {
+        Some((suffix, multiplier)) => (suffix_start + suffix.len(), *multiplier),
+        None => (end, 1.0),
+    };
+    // The decimal separator is the last '.' or ',', if it's only used once and
+    // doesn't look like it groups thousands.
+    let decimal = digits.rfind(['.', ',']).filter(|&i| {
+        let separator = &digits[i..i + 1];
+        let fraction_digits = digits.len() - i - 1;
+        digits.matches(separator).count() == 1 && (fraction_digits != 3 || suffix.is_some())
+    });
+    let mut normalised = String::with_capacity(digits.len());
+    for (i, c) in digits.char_indices() {
+        if c.is_ascii_digit() {
+            normalised.push(c);
+        } else if Some(i) == decimal {
+            normalised.push('.');
+        }
+    }
+    let number: f64 = normalised.parse().ok()?;
+    Some((&rest[..matched_len], number * multiplier))
+}
+
+/// Artists are the runs that link to a channel.
+fn parse_artists_from_runs(
+    data: &mut impl JsonCrawler,
+    runs: &str,
+) -> Result<Vec<ParsedSongArtist>> {
+    let Ok(runs) = data.borrow_pointer(runs) else {
+        return Ok(Vec::new());
+    };
+    runs.try_into_iter()?
+        .filter(|run| browse_id_starts_with(run, "UC"))
+        .map(|mut run| parse_channel_run(&mut run))
+        .collect()
+}
+
+/// Parse a run linking to a channel, keeping the page type of the link.
+fn parse_channel_run(run: &mut impl JsonCrawler) -> Result<ParsedSongArtist> {
+    let page_type = run
+        .take_value_pointer(concatcp!(NAVIGATION_BROWSE, PAGE_TYPE))
+        .ok();
+    Ok(ParsedSongArtist {
+        page_type,
+        ..parse_song_artist(run)?
+    })
+}
+
+/// The publisher of a podcast is the first run with a link.
+fn parse_publisher_from_runs(
+    data: &mut impl JsonCrawler,
+    runs: &str,
+) -> Result<Option<ParsedSongArtist>> {
+    let Ok(runs) = data.borrow_pointer(runs) else {
+        return Ok(None);
+    };
+    runs.try_into_iter()?
+        .find(|run| run.path_exists(NAVIGATION_BROWSE_ID))
+        .map(|mut run| parse_channel_run(&mut run))
+        .transpose()
+}
+
+fn browse_id_starts_with(run: &impl JsonCrawler, prefix: &str) -> bool {
+    run.borrow_value_pointer::<String>(NAVIGATION_BROWSE_ID)
+        .is_ok_and(|id| id.starts_with(prefix))
+}
+
+/// The album is the first run linking to an album that follows the artists.
+/// Some cards also reference another album, such as a compilation, before the
+/// artists. If no album link follows the artists, the first album link is used.
+/// This must be called before the artists are parsed, as that takes the artist
+/// links.
+fn parse_album_from_runs(
+    data: &mut impl JsonCrawler,
+    runs: &str,
+) -> Result<Option<ParsedSongAlbum>> {
+    let Ok(runs) = data.borrow_pointer(runs) else {
+        return Ok(None);
+    };
+    let runs = runs.try_into_iter()?.collect::<Vec<_>>();
+    let after_artists = runs
+        .iter()
+        .rposition(|run| browse_id_starts_with(run, "UC"))
+        .map_or(0, |idx| idx + 1);
+    let (following, preceding): (Vec<_>, Vec<_>) = runs
+        .into_iter()
+        .enumerate()
+        .filter(|(_, run)| browse_id_starts_with(run, "MPRE"))
+        .partition(|(idx, _)| *idx >= after_artists);
+    following
+        .into_iter()
+        .chain(preceding)
+        .next()
+        .map(|(_, run)| run)
+        .map(|mut run| -> Result<_> {
+            Ok(ParsedSongAlbum {
+                name: run.take_value_pointer("/text")?,
+                id: run.take_value_pointer::<AlbumID>(NAVIGATION_BROWSE_ID)?,
+            })
+        })
+        .transpose()
+}
@@ -0,0 +1,228 @@
+//! Builders to construct home feed results by hand, for testing code that
+//! consumes them without needing real JSON.
+use super::{HomeContent, HomeContentFlat, HomeContentKind, HomeSection, parse_duration_seconds};
+use crate::common::{
+    AlbumID, AlbumType, ArtistChannelID, Explicit, MoodCategoryParams, PlaylistID, PodcastID,
+    Thumbnail, VideoID, YoutubeID,
+};
+use crate::parse::{ParsedSongAlbum, ParsedSongArtist};
+
+/// Builder for a [`HomeSection`]. Fields that aren't set are left empty.
+/// # Usage
+/// ```
+/// use ytmapi_rs::parse::{HomeContentBuilder, HomeSectionBuilder};
+/// let section = HomeSectionBuilder::new("Quick picks")
+///     .with_content(HomeContentBuilder::song("Teardrop", "u7K72X4eo_s").build())
+///     .build();
+/// assert_eq!(section.contents.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HomeSectionBuilder {
+    section: HomeSection,
+}
+
+/// Builder for a [`HomeContent`], created using the constructor for the kind
+/// of item. Fields that aren't set are left empty, and setters for fields
+/// that don't apply to the kind of item are ignored.
+#[derive(Debug, Clone)]
+pub struct HomeContentBuilder {
+    content: HomeContentFlat,
+}
+
+impl HomeSectionBuilder {
+    pub fn new(title: impl Into<String>) -> Self {
+        HomeSectionBuilder {
+            section: HomeSection {
+                title: title.into(),
+                strapline: None,
+                thumbnails: Vec::new(),
+                animated_thumbnail: None,
+                more_params: None,
+                more_label: None,
+                shuffle_params: None,
+                tracking_params: None,
+                position: 0,
+                contents: Vec::new(),
+            },
+        }
+    }
+    pub fn with_strapline(mut self, strapline: impl Into<String>) -> Self {
+        self.section.strapline = Some(strapline.into());
+        self
+    }
+    pub fn with_thumbnails(mut self, thumbnails: Vec<Thumbnail>) -> Self {
+        self.section.thumbnails = thumbnails;
+        self
+    }
+    pub fn with_animated_thumbnail(mut self, url: impl Into<String>) -> Self {
+        self.section.animated_thumbnail = Some(url.into());
+        self
+    }
+    pub fn with_more_params(mut self, params: impl Into<String>) -> Self {
+        self.section.more_params = Some(MoodCategoryParams::from_raw(params.into()));
+        self
+    }
+    pub fn with_more_label(mut self, label: impl Into<String>) -> Self {
+        self.section.more_label = Some(label.into());
+        self
+    }
+    pub fn with_shuffle_params(mut self, params: impl Into<String>) -> Self {
+        self.section.shuffle_params = Some(MoodCategoryParams::from_raw(params.into()));
+        self
+    }
+    pub fn with_tracking_params(mut self, params: impl Into<String>) -> Self {
+        self.section.tracking_params = Some(params.into());
+        self
+    }
+    pub fn with_position(mut self, position: usize) -> Self {
+        self.section.position = position;
+        self
+    }
+    /// Append an item to the section.
+    pub fn with_content(mut self, content: HomeContent) -> Self {
+        self.section.contents.push(content);
+        self
+    }
+    /// Append several items to the section.
+    pub fn with_contents(mut self, contents: impl IntoIterator<Item = HomeContent>) -> Self {
+        self.section.contents.extend(contents);
+        self
+    }
+    pub fn build(self) -> HomeSection {
+        self.section
+    }
+}
+
+impl HomeContentBuilder {
+    fn new(kind: HomeContentKind, title: impl Into<String>) -> Self {
+        HomeContentBuilder {
+            content: HomeContentFlat::empty(kind, title.into()),
+        }
+    }
+    pub fn song(title: impl Into<String>, video_id: impl Into<String>) -> Self {
+        let mut builder = Self::new(HomeContentKind::Song, title);
+        builder.content.video_id = Some(VideoID::from_raw(video_id.into()));
+        builder
+    }
+    pub fn video(title: impl Into<String>, video_id: impl Into<String>) -> Self {
+        let mut builder = Self::new(HomeContentKind::Video, title);
+        builder.content.video_id = Some(VideoID::from_raw(video_id.into()));
+        builder
+    }
+    pub fn album(title: impl Into<String>, album_id: impl Into<String>) -> Self {
+        let mut builder = Self::new(HomeContentKind::Album, title);
+        builder.content.album_id = Some(AlbumID::from_raw(album_id.into()));
+        builder
+    }
+    /// The browse id is derived from `playlist_id`.
+    pub fn playlist(title: impl Into<String>, playlist_id: impl Into<String>) -> Self {
+        let mut builder = Self::new(HomeContentKind::Playlist, title);
+        let playlist_id = playlist_id.into();
+        builder.content.browse_id = Some(format!("VL{playlist_id}"));
+        builder.content.playlist_id = Some(PlaylistID::from_raw(playlist_id));
+        builder
+    }
+    pub fn artist(title: impl Into<String>, channel_id: impl Into<String>) -> Self {
+        let mut builder = Self::new(HomeContentKind::Artist, title);
+        builder.content.channel_id = Some(ArtistChannelID::from_raw(channel_id.into()));
+        builder
+    }
+    pub fn watch_playlist(title: impl Into<String>, playlist_id: impl Into<String>) -> Self {
+        let mut builder = Self::new(HomeContentKind::WatchPlaylist, title);
+        builder.content.playlist_id = Some(PlaylistID::from_raw(playlist_id.into()));
+        builder
+    }
+    pub fn podcast(title: impl Into<String>, podcast_id: impl Into<String>) -> Self {
+        let mut builder = Self::new(HomeContentKind::Podcast, title);
+        builder.content.podcast_id = Some(PodcastID::from_raw(podcast_id.into()));
+        builder
+    }
+    /// Append an artist, or playlist author. Pass `None` as `channel_id` for
+    /// an artist without a link.
+    /// Only the first artist is kept as the publisher of a podcast.
+    pub fn with_artist(mut self, name: impl Into<String>, channel_id: Option<&str>) -> Self {
+        self.content.artists.push(ParsedSongArtist {
+            name: name.into(),
+            id: channel_id.map(|id| ArtistChannelID::from_raw(id.to_string())),
+            page_type: None,
+        });
+        self
+    }
+    pub fn with_album(mut self, name: impl Into<String>, album_id: impl Into<String>) -> Self {
+        self.content.album = Some(ParsedSongAlbum {
+            name: name.into(),
+            id: AlbumID::from_raw(album_id.into()),
+        });
+        self
+    }
+    pub fn with_album_type(mut self, album_type: AlbumType) -> Self {
+        self.content.album_type = Some(album_type);
+        self
+    }
+    pub fn with_year(mut self, year: impl Into<String>) -> Self {
+        self.content.year = Some(year.into());
+        self
+    }
+    pub fn with_track_count(mut self, track_count: u32) -> Self {
+        self.content.track_count = Some(track_count);
+        self
+    }
+    pub fn with_episode_count(mut self, episode_count: u32) -> Self {
+        self.content.episode_count = Some(episode_count);
+        self
+    }
+    pub fn with_explicit(mut self, explicit: Explicit) -> Self {
+        self.content.explicit = Some(explicit);
+        self
+    }
+    pub fn with_premium_only(mut self, is_premium_only: bool) -> Self {
+        self.content.is_premium_only = is_premium_only;
+        self
+    }
+    /// Set the duration as displayed, e.g "3:45". The duration in seconds is
+    /// derived from it.
+    pub fn with_duration(mut self, duration: impl Into<String>) -> Self {
+        let duration = duration.into();
+        self.content.duration_seconds = parse_duration_seconds(&duration);
+        self.content.duration = Some(duration);
+        self
+    }
+    pub fn with_views(mut self, views: impl Into<String>) -> Self {
+        self.content.views = Some(views.into());
+        self
+    }
+    pub fn with_subscribers(mut self, subscribers: impl Into<String>) -> Self {
+        self.content.subscribers = Some(subscribers.into());
+        self
+    }
+    pub fn with_subscribe_params(
+        mut self,
+        subscribe: impl Into<String>,
+        unsubscribe: impl Into<String>,
+    ) -> Self {
+        self.content.subscribe_params = Some(subscribe.into());
+        self.content.unsubscribe_params = Some(unsubscribe.into());
+        self
+    }
+    pub fn with_thumbnails(mut self, thumbnails: Vec<Thumbnail>) -> Self {
+        self.content.thumbnails = thumbnails;
+        self
+    }
+    pub fn with_subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.content.subtitle = Some(subtitle.into());
+        self
+    }
+    pub fn with_tracking_params(mut self, params: impl Into<String>) -> Self {
+        self.content.tracking_params = Some(params.into());
+        self
+    }
+    pub fn with_from_library(mut self, from_library: bool) -> Self {
+        self.content.from_library = from_library;
+        self
+    }
+    pub fn build(self) -> HomeContent {
+        self.content
+            .into_content()
+            .expect("Constructors set the id required by each kind")
+    }
+}
@@ -0,0 +1,367 @@
+use super::{
+    HomeAlbum, HomeArtist, HomeAuthorKind, HomeContent, HomeContentKind, HomeMoodChip,
+    HomePlaylist, HomePodcast, HomeSection, HomeSections, HomeSong, HomeVideo, HomeWatchPlaylist,
+    ParseWarning,
+};
+use crate::common::{
+    AlbumID, AlbumType, ArtistChannelID, Explicit, LibraryManager, LikeFeedbackTokens,
+    MoodCategoryParams, PlaylistID, PodcastID, Thumbnail, VideoID,
+};
+use crate::parse::{ParsedSongAlbum, ParsedSongArtist};
+use serde::{Deserialize, Serialize};
+
+#[derive(PartialEq, Debug, Clone, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+/// A representation of [`HomeSections`] without tagged unions, for use with
+/// formats that can't represent them.
+/// Can be converted back using [`HomeSectionsFlat::into_sections`].
+/// Unlike [`HomeSections`], empty optional fields are still serialized, so that
+/// every item has the same shape.
+pub struct HomeSectionsFlat {
+    pub chips: Vec<HomeMoodChip>,
+    pub sections: Vec<HomeSectionFlat>,
+    pub active_chip: Option<HomeMoodChip>,
+    pub more_moods_params: Option<MoodCategoryParams<'static>>,
+    pub warnings: Vec<ParseWarning>,
+    pub unknown_renderers: Vec<String>,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct HomeSectionFlat {
+    pub title: String,
+    pub strapline: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub animated_thumbnail: Option<String>,
+    pub more_params: Option<MoodCategoryParams<'static>>,
+    pub more_label: Option<String>,
+    pub shuffle_params: Option<MoodCategoryParams<'static>>,
+    pub tracking_params: Option<String>,
+    pub position: usize,
+    pub contents: Vec<HomeContentFlat>,
+}
+
+#[derive(PartialEq, Debug, Clone, Deserialize, Serialize)]
+#[non_exhaustive]
+/// A single item of a home section. Fields not applicable to `kind` are None
+/// or empty.
+pub struct HomeContentFlat {
+    pub kind: HomeContentKind,
+    pub title: String,
+    pub video_id: Option<VideoID<'static>>,
+    pub album_id: Option<AlbumID<'static>>,
+    pub playlist_id: Option<PlaylistID<'static>>,
+    /// The raw browse id of playlists.
+    pub browse_id: Option<String>,
+    pub channel_id: Option<ArtistChannelID<'static>>,
+    pub podcast_id: Option<PodcastID<'static>>,
+    /// Artists for songs, videos and albums, authors for playlists, or the
+    /// publisher of a podcast.
+    pub artists: Vec<ParsedSongArtist>,
+    pub author_kind: Option<HomeAuthorKind>,
+    pub album: Option<ParsedSongAlbum>,
+    pub album_type: Option<AlbumType>,
+    pub year: Option<String>,
+    pub track_count: Option<u32>,
+    pub episode_count: Option<u32>,
+    pub explicit: Option<Explicit>,
+    pub is_premium_only: bool,
+    pub play_params: Option<String>,
+    pub play_video_id: Option<VideoID<'static>>,
+    pub duration: Option<String>,
+    pub duration_seconds: Option<u32>,
+    pub library_management: Option<LibraryManager>,
+    pub like_feedback_tokens: Option<LikeFeedbackTokens>,
+    pub views: Option<String>,
+    pub subscribers: Option<String>,
+    pub subscribe_params: Option<String>,
+    pub unsubscribe_params: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub subtitle: Option<String>,
+    pub tracking_params: Option<String>,
+    pub from_library: bool,
+}
+
+impl HomeSections {
+    /// Convert to a representation without tagged unions.
+    pub fn to_flat(&self) -> HomeSectionsFlat {
+        HomeSectionsFlat {
+            chips: self.chips.clone(),
+            sections: self.sections.iter().map(HomeSectionFlat::from).collect(),
+            active_chip: self.active_chip.clone(),
+            more_moods_params: self.more_moods_params.clone(),
+            warnings: self.warnings.clone(),
+            unknown_renderers: self.unknown_renderers.clone(),
+        }
+    }
+}
+
+impl HomeSectionsFlat {
+    /// Convert back to [`HomeSections`].
+    /// Returns None if an item is missing a field required by its kind, e.g a
+    /// song without a video_id.
+    pub fn into_sections(self) -> Option<HomeSections> {
+        let sections = self
+            .sections
+            .into_iter()
+            .map(HomeSectionFlat::into_section)
+            .collect::<Option<_>>()?;
+        Some(HomeSections {
+            chips: self.chips,
+            sections,
+            active_chip: self.active_chip,
+            more_moods_params: self.more_moods_params,
+            warnings: self.warnings,
+            unknown_renderers: self.unknown_renderers,
+        })
+    }
+}
+
+impl HomeSectionFlat {
+    fn into_section(self) -> Option<HomeSection> {
+        let contents = self
+            .contents
+            .into_iter()
+            .map(HomeContentFlat::into_content)
+            .collect::<Option<_>>()?;
+        Some(HomeSection {
+            title: self.title,
+            strapline: self.strapline,
+            thumbnails: self.thumbnails,
+            animated_thumbnail: self.animated_thumbnail,
+            more_params: self.more_params,
+            more_label: self.more_label,
+            shuffle_params: self.shuffle_params,
+            tracking_params: self.tracking_params,
+            position: self.position,
+            contents,
+        })
+    }
+}
+
+impl From<&HomeSection> for HomeSectionFlat {
+    fn from(section: &HomeSection) -> Self {
+        HomeSectionFlat {
+            title: section.title.clone(),
+            strapline: section.strapline.clone(),
+            thumbnails: section.thumbnails.clone(),
+            animated_thumbnail: section.animated_thumbnail.clone(),
+            more_params: section.more_params.clone(),
+            more_label: section.more_label.clone(),
+            shuffle_params: section.shuffle_params.clone(),
+            tracking_params: section.tracking_params.clone(),
+            position: section.position,
+            contents: section.contents.iter().map(HomeContentFlat::from).collect(),
+        }
+    }
+}
+
+impl HomeContentFlat {
+    pub(super) fn empty(kind: HomeContentKind, title: String) -> Self {
+        HomeContentFlat {
+            kind,
+            title,
+            video_id: None,
+            album_id: None,
+            playlist_id: None,
+            browse_id: None,
+            channel_id: None,
+            podcast_id: None,
+            artists: Vec::new(),
+            author_kind: None,
+            album: None,
+            album_type: None,
+            year: None,
+            track_count: None,
+            episode_count: None,
+            explicit: None,
+            is_premium_only: false,
+            play_params: None,
+            play_video_id: None,
+            duration: None,
+            duration_seconds: None,
+            library_management: None,
+            like_feedback_tokens: None,
+            views: None,
+            subscribers: None,
+            subscribe_params: None,
+            unsubscribe_params: None,
+            thumbnails: Vec::new(),
+            subtitle: None,
+            tracking_params: None,
+            from_library: false,
+        }
+    }
+    pub(super) fn into_content(self) -> Option<HomeContent> {
+        let content = match self.kind {
+            HomeContentKind::Song => HomeContent::Song(HomeSong {
+                title: self.title,
+                video_id: self.video_id?,
+                artists: self.artists,
+                album: self.album,
+                explicit: self.explicit,
+                is_premium_only: self.is_premium_only,
+                duration: self.duration,
+                duration_seconds: self.duration_seconds,
+                library_management: self.library_management,
+                like_feedback_tokens: self.like_feedback_tokens,
+                thumbnails: self.thumbnails,
+                subtitle: self.subtitle,
+                from_library: self.from_library,
+            }),
+            HomeContentKind::Video => HomeContent::Video(HomeVideo {
+                title: self.title,
+                video_id: self.video_id?,
+                artists: self.artists,
+                explicit: self.explicit,
+                is_premium_only: self.is_premium_only,
+                views: self.views,
+                thumbnails: self.thumbnails,
+                subtitle: self.subtitle,
+                from_library: self.from_library,
+            }),
+            HomeContentKind::Album => HomeContent::Album(HomeAlbum {
+                title: self.title,
+                album_id: self.album_id?,
+                album_type: self.album_type,
+                artists: self.artists,
+                year: self.year,
+                track_count: self.track_count,
+                explicit: self.explicit,
+                is_premium_only: self.is_premium_only,
+                play_params: self.play_params,
+                play_video_id: self.play_video_id,
+                thumbnails: self.thumbnails,
+                subtitle: self.subtitle,
+                from_library: self.from_library,
+            }),
+            HomeContentKind::Playlist => HomeContent::Playlist(HomePlaylist {
+                title: self.title,
+                playlist_id: self.playlist_id?,
+                browse_id: self.browse_id.unwrap_or_default(),
+                author: self.artists,
+                author_kind: self.author_kind,
+                play_params: self.play_params,
+                play_video_id: self.play_video_id,
+                thumbnails: self.thumbnails,
+                subtitle: self.subtitle,
+                from_library: self.from_library,
+            }),
+            HomeContentKind::Artist => HomeContent::Artist(HomeArtist {
+                title: self.title,
+                channel_id: self.channel_id?,
+                subscribers: self.subscribers,
+                subscribe_params: self.subscribe_params,
+                unsubscribe_params: self.unsubscribe_params,
+                thumbnails: self.thumbnails,
+                subtitle: self.subtitle,
+                from_library: self.from_library,
+            }),
+            HomeContentKind::WatchPlaylist => HomeContent::WatchPlaylist(HomeWatchPlaylist {
+                title: self.title,
+                playlist_id: self.playlist_id?,
+                thumbnails: self.thumbnails,
+                subtitle: self.subtitle,
+                tracking_params: self.tracking_params,
+                from_library: self.from_library,
+            }),
+            HomeContentKind::Podcast => HomeContent::Podcast(HomePodcast {
+                title: self.title,
+                podcast_id: self.podcast_id?,
+                publisher: self.artists.into_iter().next(),
+                episode_count: self.episode_count,
+                thumbnails: self.thumbnails,
+                subtitle: self.subtitle,
+                from_library: self.from_library,
+            }),
+        };
+        Some(content)
+    }
+}
+
+impl From<&HomeContent> for HomeContentFlat {
+    fn from(content: &HomeContent) -> Self {
+        match content.clone() {
+            HomeContent::Song(song) => HomeContentFlat {
+                video_id: Some(song.video_id),
+                artists: song.artists,
+                album: song.album,
+                explicit: song.explicit,
+                is_premium_only: song.is_premium_only,
+                duration: song.duration,
+                duration_seconds: song.duration_seconds,
+                library_management: song.library_management,
+                like_feedback_tokens: song.like_feedback_tokens,
+                thumbnails: song.thumbnails,
+                subtitle: song.subtitle,
+                from_library: song.from_library,
+                ..HomeContentFlat::empty(HomeContentKind::Song, song.title)
+            },
+            HomeContent::Video(video) => HomeContentFlat {
+                video_id: Some(video.video_id),
+                artists: video.artists,
+                explicit: video.explicit,
+                is_premium_only: video.is_premium_only,
+                views: video.views,
+                thumbnails: video.thumbnails,
+                subtitle: video.subtitle,
+                from_library: video.from_library,
+                ..HomeContentFlat::empty(HomeContentKind::Video, video.title)
+            },
+            HomeContent::Album(album) => HomeContentFlat {
+                album_id: Some(album.album_id),
+                album_type: album.album_type,
+                artists: album.artists,
+                year: album.year,
+                track_count: album.track_count,
+                explicit: album.explicit,
+                is_premium_only: album.is_premium_only,
+                play_params: album.play_params,
+                play_video_id: album.play_video_id,
+                thumbnails: album.thumbnails,
+                subtitle: album.subtitle,
+                from_library: album.from_library,
+                ..HomeContentFlat::empty(HomeContentKind::Album, album.title)
+            },
+            HomeContent::Playlist(playlist) => HomeContentFlat {
+                playlist_id: Some(playlist.playlist_id),
+                browse_id: Some(playlist.browse_id),
+                artists: playlist.author,
+                author_kind: playlist.author_kind,
+                play_params: playlist.play_params,
+                play_video_id: playlist.play_video_id,
+                thumbnails: playlist.thumbnails,
+                subtitle: playlist.subtitle,
+                from_library: playlist.from_library,
+                ..HomeContentFlat::empty(HomeContentKind::Playlist, playlist.title)
+            },
+            HomeContent::Artist(artist) => HomeContentFlat {
+                channel_id: Some(artist.channel_id),
+                subscribers: artist.subscribers,
+                subscribe_params: artist.subscribe_params,
+                unsubscribe_params: artist.unsubscribe_params,
+                thumbnails: artist.thumbnails,
+                subtitle: artist.subtitle,
+                from_library: artist.from_library,
+                ..HomeContentFlat::empty(HomeContentKind::Artist, artist.title)
+            },
+            HomeContent::WatchPlaylist(watch_playlist) => HomeContentFlat {
+                playlist_id: Some(watch_playlist.playlist_id),
+                thumbnails: watch_playlist.thumbnails,
+                subtitle: watch_playlist.subtitle,
+                tracking_params: watch_playlist.tracking_params,
+                from_library: watch_playlist.from_library,
+                ..HomeContentFlat::empty(HomeContentKind::WatchPlaylist, watch_playlist.title)
+            },
+            HomeContent::Podcast(podcast) => HomeContentFlat {
+                podcast_id: Some(podcast.podcast_id),
+                artists: podcast.publisher.into_iter().collect(),
+                episode_count: podcast.episode_count,
+                thumbnails: podcast.thumbnails,
+                subtitle: podcast.subtitle,
+                from_library: podcast.from_library,
+                ..HomeContentFlat::empty(HomeContentKind::Podcast, podcast.title)
+            },
+        }
+    }
+}
@@ -0,0 +1,1967 @@
+use super::{
+    Badge, CachedHomeSections, HomeContent, HomeContentKey, HomeContentKind, HomeContentVisitor,
+    HomeMoodChip, HomeParseOptions, HomeRow, HomeSection, HomeSectionKind, HomeSections, HomeSong,
+    HomeVideo, MetricLexicon, ThumbnailAspect, is_year, parse_badges, parse_localized_number,
+};
+use crate::RawResult;
+use crate::auth::BrowserToken;
+use crate::common::{
+    ArtistChannelID, ContinuationParams, Explicit, LibraryStatus, LikeFeedbackTokens, LikeStatus,
+    MoodCategoryParams, VideoID, YoutubeID,
+};
+use crate::continuations::ParseFromContinuable;
+use crate::error::ErrorKind;
+use crate::query::{
+    GetContinuationsQuery, GetExploreQuery, GetHomeChipsQuery, GetHomeQuery, GetWatchPlaylistQuery,
+    PostQuery,
+};
+
+fn mock_section(title: &str) -> HomeSection {
+    HomeSection {
+        title: title.to_string(),
+        strapline: None,
+        thumbnails: Vec::new(),
+        animated_thumbnail: None,
+        more_params: None,
+        more_label: None,
+        shuffle_params: None,
+        tracking_params: None,
+        position: 0,
+        contents: Vec::new(),
+    }
+}
+
+/// Read a fixture's raw source, for tests that parse it with other queries.
+async fn read_fixture(path: &str) -> String {
+    tokio::fs::read_to_string(path)
+        .await
+        .expect("Expect file read to pass during tests")
+}
+
+/// Parse a home feed fixture with the default query.
+async fn parse_home_fixture(path: &str) -> HomeSections {
+    let source = read_fixture(path).await;
+    crate::process_json::<_, BrowserToken>(source, GetHomeQuery::new()).unwrap()
+}
+
+#[tokio::test]
+async fn test_get_home() {
+    parse_test!(
+        "./test_json/get_home_20261014.json",
+        "./test_json/get_home_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn test_get_home_tracing_spans() {
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+    /// Collects the name of each span, alongside each field recorded on it.
+    #[derive(Clone, Default)]
+    struct SpanRecorder(Arc<Mutex<Vec<String>>>);
+    struct FieldVisitor<'a>(&'a str, &'a Mutex<Vec<String>>);
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.1
+                .lock()
+                .unwrap()
+                .push(format!("{}.{}={:?}", self.0, field.name(), value));
+        }
+    }
+    impl<S> Layer<S> for SpanRecorder
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+            let name = attrs.metadata().name();
+            self.0.lock().unwrap().push(name.to_string());
+            attrs.record(&mut FieldVisitor(name, &self.0));
+        }
+        fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+            let name = ctx.span(id).unwrap().name();
+            values.record(&mut FieldVisitor(name, &self.0));
+        }
+    }
+    let source = read_fixture("./test_json/get_home_malformed_20261014.json").await;
+    let recorder = SpanRecorder::default();
+    let subscriber = tracing_subscriber::registry().with(recorder.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        crate::process_json::<_, BrowserToken>(source, GetHomeQuery::new()).unwrap();
+    });
+    let recorded = recorder.0.lock().unwrap();
+    assert!(recorded.iter().any(|r| r == "parse_from_continuable"));
+    assert!(
+        recorded
+            .iter()
+            .any(|r| r == "parse_home_contents.sections=2")
+    );
+    assert!(
+        recorded
+            .iter()
+            .any(|r| r == "parse_home_contents.skipped=2")
+    );
+    assert!(
+        recorded
+            .iter()
+            .any(|r| r == "parse_carousel_section.title=\"Listen again\"")
+    );
+}
+#[tokio::test]
+async fn test_get_home_continuation() {
+    parse_continuations_test!(
+        "./test_json/get_home_continuation_20261014.json",
+        "./test_json/get_home_continuation_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_home_snapshots() {
+    use futures::StreamExt;
+    let query = GetHomeQuery::new();
+    let first = read_fixture("./test_json/get_home_20261014.json").await;
+    let first = crate::process_json::<_, BrowserToken>(first, query.clone()).unwrap();
+    let continuation = read_fixture("./test_json/get_home_continuation_20261014.json").await;
+    let continuations_query = GetContinuationsQuery::new_mock_unchecked(&query);
+    let processed = RawResult::<_, BrowserToken>::from_raw(continuation, &continuations_query)
+        .process()
+        .unwrap();
+    let (continuation, _) = HomeSections::parse_continuation(processed).unwrap();
+    assert!(!first.chips.is_empty() && continuation.chips.is_empty());
+    let pages = [Ok(first.clone()), Ok(continuation.clone())];
+    let snapshots: Vec<_> = super::home_snapshots(futures::stream::iter(pages))
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    let [first_snapshot, second_snapshot] = snapshots.as_slice() else {
+        panic!("Expected a snapshot per page, got {snapshots:#?}");
+    };
+    assert_eq!(first_snapshot, &first);
+    assert_eq!(second_snapshot.chips, first.chips);
+    assert!(
+        second_snapshot
+            .sections
+            .starts_with(&first_snapshot.sections)
+    );
+    // Positions of the continuation's sections continue on from the first page.
+    let offset_continuation: Vec<_> = continuation
+        .sections
+        .iter()
+        .map(|section| HomeSection {
+            position: section.position + first.sections.len(),
+            ..section.clone()
+        })
+        .collect();
+    assert_eq!(
+        second_snapshot.sections[first.sections.len()..],
+        offset_continuation
+    );
+}
+#[tokio::test]
+async fn test_home_section_positions_across_pages() {
+    use futures::StreamExt;
+    let query = GetHomeQuery::new();
+    let first = read_fixture("./test_json/get_home_20261014.json").await;
+    let first = crate::process_json::<_, BrowserToken>(first, query.clone()).unwrap();
+    let continuation = read_fixture("./test_json/get_home_continuation_20261014.json").await;
+    let continuations_query = GetContinuationsQuery::new_mock_unchecked(&query);
+    let processed = RawResult::<_, BrowserToken>::from_raw(continuation, &continuations_query)
+        .process()
+        .unwrap();
+    let (continuation, _) = HomeSections::parse_continuation(processed).unwrap();
+    let positions =
+        |feed: &HomeSections| feed.sections.iter().map(|s| s.position).collect::<Vec<_>>();
+    // Each page is numbered from 0 when parsed on its own.
+    assert_eq!(
+        positions(&first),
+        (0..first.sections.len()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        positions(&continuation),
+        (0..continuation.sections.len()).collect::<Vec<_>>()
+    );
+    let pages = [Ok(first.clone()), Ok(continuation.clone())];
+    let merged = super::home_snapshots(futures::stream::iter(pages))
+        .map(Result::unwrap)
+        .collect::<Vec<_>>()
+        .await
+        .pop()
+        .unwrap();
+    let total = first.sections.len() + continuation.sections.len();
+    assert!(!continuation.sections.is_empty());
+    assert_eq!(positions(&merged), (0..total).collect::<Vec<_>>());
+}
+#[tokio::test]
+async fn test_get_home_continuation_empty_page() {
+    // Case where a continuation page contains no sections, but there are still
+    // more pages to fetch.
+    let source = read_fixture("./test_json/get_home_continuation_empty_20261014.json").await;
+    let query = GetHomeQuery::new();
+    let continuations_query = GetContinuationsQuery::new_mock_unchecked(&query);
+    let processed = RawResult::<_, BrowserToken>::from_raw(source, &continuations_query)
+        .process()
+        .unwrap();
+    let (output, params) = HomeSections::parse_continuation(processed).unwrap();
+    assert!(output.sections.is_empty());
+    assert_eq!(
+        params,
+        Some(ContinuationParams::from_raw(
+            "4qmFsgIMEgpGRW11c2ljX2hvbWU%3D_next"
+        ))
+    );
+}
+#[tokio::test]
+async fn test_get_home_continuation_done() {
+    // Case where the end of the feed has been reached.
+    let source = read_fixture("./test_json/get_home_continuation_done_20261014.json").await;
+    let query = GetHomeQuery::new();
+    let continuations_query = GetContinuationsQuery::new_mock_unchecked(&query);
+    let processed = RawResult::<_, BrowserToken>::from_raw(source, &continuations_query)
+        .process()
+        .unwrap();
+    let (output, params) = HomeSections::parse_continuation(processed).unwrap();
+    assert_eq!(output, HomeSections::default());
+    assert_eq!(params, None);
+}
+#[tokio::test]
+async fn test_get_home_continuation_blank_params() {
+    // Blank continuation params would return the same page, so should end the
+    // stream.
+    let source = read_fixture("./test_json/get_home_continuation_blank_20261014.json").await;
+    let query = GetHomeQuery::new();
+    let continuations_query = GetContinuationsQuery::new_mock_unchecked(&query);
+    let processed = RawResult::<_, BrowserToken>::from_raw(source, &continuations_query)
+        .process()
+        .unwrap();
+    let (_, next) = GetContinuationsQuery::from_continuation::<HomeSections>(processed).unwrap();
+    assert!(next.is_none());
+}
+#[tokio::test]
+async fn test_get_home_signed_out() {
+    let source = read_fixture("./test_json/get_home_signed_out_20261014.json").await;
+    let err = crate::process_json::<_, BrowserToken>(source, GetHomeQuery::new()).unwrap_err();
+    assert!(matches!(err.into_kind(), ErrorKind::AuthRequired));
+}
+#[test]
+fn test_get_home_no_contents_is_not_auth_required() {
+    let source = r#"{"responseContext": {}}"#.to_string();
+    let err = crate::process_json::<_, BrowserToken>(source, GetHomeQuery::new()).unwrap_err();
+    assert!(matches!(err.into_kind(), ErrorKind::JsonParsing(_)));
+}
+#[tokio::test]
+async fn test_get_explore() {
+    parse_test!(
+        "./test_json/get_explore_20261014.json",
+        "./test_json/get_explore_20261014_output.txt",
+        GetExploreQuery,
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_track_card() {
+    // Case where a song card has a track page type, instead of no page type.
+    parse_test!(
+        "./test_json/get_home_track_20261014.json",
+        "./test_json/get_home_track_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_song_duration() {
+    let output = parse_home_fixture("./test_json/get_home_duration_20261014.json").await;
+    let durations: Vec<_> = output
+        .items()
+        .filter_map(|item| match item {
+            HomeContent::Song(song) => Some((song.duration.as_deref(), song.duration_seconds)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        durations,
+        [
+            (Some("6:19"), Some(379)),
+            (None, None),
+            (Some("1:05:30"), Some(3930))
+        ]
+    );
+}
+#[tokio::test]
+async fn test_get_home_shuffle_params() {
+    let output = parse_home_fixture("./test_json/get_home_shuffle_20261014.json").await;
+    let shuffle_params: Vec<_> = output
+        .sections
+        .iter()
+        .map(|section| section.shuffle_params.as_ref())
+        .collect();
+    assert_eq!(
+        shuffle_params,
+        [Some(&MoodCategoryParams::from_raw("wAEB8gECKAE%3D")), None]
+    );
+}
+#[tokio::test]
+async fn test_get_home_artist_subscribers() {
+    // Subscriber count may not be the first subtitle run.
+    let output = parse_home_fixture("./test_json/get_home_artist_subscribers_20261014.json").await;
+    let subscribers: Vec<_> = output
+        .items()
+        .filter_map(|item| match item {
+            HomeContent::Artist(artist) => Some(artist.subscribers.as_deref()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(subscribers, [Some("1.2M"), Some("890K"), None]);
+}
+#[tokio::test]
+async fn test_get_home_artist_subscribe_params() {
+    let output = parse_home_fixture("./test_json/get_home_artist_subscribe_20261014.json").await;
+    let params: Vec<_> = output
+        .items()
+        .filter_map(HomeContent::as_artist)
+        .map(|artist| {
+            (
+                artist.subscribe_params.as_deref(),
+                artist.unsubscribe_params.as_deref(),
+            )
+        })
+        .collect();
+    // Second card has no subscribe button.
+    assert_eq!(params, [(Some("EgIIAhgA"), Some("CgIIAhgA")), (None, None)]);
+}
+#[tokio::test]
+async fn test_get_home_two_column_items() {
+    // All but the last card use the two column renderer, which links from the
+    // card instead of its title.
+    parse_test!(
+        "./test_json/get_home_two_column_20261014.json",
+        "./test_json/get_home_two_column_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_premium_only() {
+    let output = parse_home_fixture("./test_json/get_home_premium_20261014.json").await;
+    let premium: Vec<_> = output
+        .items()
+        .map(|item| match item {
+            HomeContent::Song(song) => (HomeContentKind::Song, song.is_premium_only),
+            HomeContent::Video(video) => (HomeContentKind::Video, video.is_premium_only),
+            HomeContent::Album(album) => (HomeContentKind::Album, album.is_premium_only),
+            _ => panic!("Expected only songs, videos and albums, got {item:?}"),
+        })
+        .collect();
+    assert_eq!(
+        premium,
+        [
+            (HomeContentKind::Song, true),
+            (HomeContentKind::Video, true),
+            (HomeContentKind::Album, true),
+            (HomeContentKind::Song, false),
+        ]
+    );
+    // The Premium badge doesn't mark an item as explicit.
+    assert!(output.items().all(|item| !item.is_explicit()));
+}
+#[tokio::test]
+async fn test_get_home_multiple_album_links() {
+    // Album following the artists is preferred over an earlier album link, and
+    // the earlier link is used only if no album follows the artists.
+    let output = parse_home_fixture("./test_json/get_home_two_albums_20261014.json").await;
+    let albums: Vec<_> = output
+        .items()
+        .filter_map(|item| match item {
+            HomeContent::Song(song) => song.album.as_ref().map(|album| album.name.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(albums, ["Mezzanine", "Trip Hop Classics"]);
+}
+#[tokio::test]
+async fn test_get_home_album_year() {
+    // Cases where the year is at different positions in the subtitle, and where
+    // the artist name is also four digits.
+    let output = parse_home_fixture("./test_json/get_home_album_year_20261014.json").await;
+    let years: Vec<_> = output
+        .items()
+        .map(|item| item.as_album().unwrap().year.as_deref())
+        .collect();
+    assert_eq!(years, [Some("1997"), Some("2000"), Some("2019"), None]);
+}
+#[tokio::test]
+async fn test_get_home_header_thumbnails() {
+    let output = parse_home_fixture("./test_json/get_home_header_thumbnails_20261014.json").await;
+    let section = &output.sections[0];
+    let widths: Vec<_> = section.thumbnails.iter().map(|t| t.width).collect();
+    assert_eq!(widths, [60, 120, 544]);
+    assert_eq!(section.thumbnail(), section.thumbnails.first());
+    assert_eq!(output.sections[1].thumbnail(), None);
+}
+#[tokio::test]
+async fn test_get_home_animated_thumbnail() {
+    let output = parse_home_fixture("./test_json/get_home_animated_thumbnail_20261014.json").await;
+    assert_eq!(
+        output.sections[0].animated_thumbnail.as_deref(),
+        Some("https://i.ytimg.com/an_webp/mock_massive/mqdefault_6s.webp")
+    );
+    assert_eq!(output.sections[1].animated_thumbnail, None);
+}
+#[tokio::test]
+async fn test_get_home_localised_metrics() {
+    let source = read_fixture("./test_json/get_home_locale_metrics_20261014.json").await;
+    let metrics = |home: HomeSections| -> Vec<Option<String>> {
+        home.items()
+            .map(|item| match item {
+                HomeContent::Video(video) => video.views.clone(),
+                HomeContent::Artist(artist) => artist.subscribers.clone(),
+                _ => panic!("Expected only videos and artists, got {item:?}"),
+            })
+            .collect()
+    };
+    // Built-in lexicon contains all supported locales.
+    let output =
+        crate::process_json::<_, BrowserToken>(source.clone(), GetHomeQuery::new()).unwrap();
+    // Abbreviations separated from the count by a space are kept.
+    assert_eq!(
+        metrics(output.clone()),
+        [
+            Some("12 M".into()),
+            Some("3,4 Mio.".into()),
+            Some("1,2 M".into())
+        ]
+    );
+    let views: Vec<_> = output
+        .items()
+        .filter_map(HomeContent::as_video)
+        .map(HomeVideo::view_count)
+        .collect();
+    assert_eq!(views, [Some(12_000_000), Some(3_400_000)]);
+    let subscribers = output
+        .items()
+        .find_map(HomeContent::as_artist)
+        .and_then(|artist| artist.subscriber_count());
+    assert_eq!(subscribers, Some(1_200_000));
+    let query = GetHomeQuery::new().with_lexicon(MetricLexicon::for_locale("fr-FR").unwrap());
+    let output = crate::process_json::<_, BrowserToken>(source.clone(), query).unwrap();
+    assert_eq!(
+        metrics(output),
+        [Some("12 M".into()), None, Some("1,2 M".into())]
+    );
+    let query = GetHomeQuery::new().with_lexicon(MetricLexicon::new(["Aufrufe"], ["Abonnenten"]));
+    let output = crate::process_json::<_, BrowserToken>(source, query).unwrap();
+    assert_eq!(metrics(output), [None, Some("3,4 Mio.".into()), None]);
+}
+#[test]
+fn test_is_year() {
+    assert!(is_year("2019"));
+    assert!(is_year("1998"));
+    // Numbers outside the range of release years, or with other text.
+    assert!(!is_year("1234"));
+    assert!(!is_year("24"));
+    assert!(!is_year("2019 songs"));
+    assert!(!is_year("2K"));
+    assert!(!is_year("Blink-182"));
+}
+#[test]
+fn test_parse_localized_number() {
+    // US grouping and abbreviations.
+    assert_eq!(parse_localized_number("1,234,567 views"), Some(1_234_567.0));
+    assert_eq!(parse_localized_number("1.2M views"), Some(1_200_000.0));
+    assert_eq!(parse_localized_number("12K"), Some(12_000.0));
+    assert_eq!(parse_localized_number("12 songs"), Some(12.0));
+    // European grouping and abbreviations.
+    assert_eq!(
+        parse_localized_number("1.234.567 Aufrufe"),
+        Some(1_234_567.0)
+    );
+    assert_eq!(parse_localized_number("1 234 567 vues"), Some(1_234_567.0));
+    assert_eq!(parse_localized_number("1\u{202f}234 vues"), Some(1_234.0));
+    assert_eq!(
+        parse_localized_number("3,4 Mio. Aufrufe"),
+        Some(3_400_000.0)
+    );
+    assert_eq!(parse_localized_number("1,5 mil"), Some(1_500.0));
+    // Indian grouping and abbreviations.
+    assert_eq!(parse_localized_number("12,34,567 views"), Some(1_234_567.0));
+    assert_eq!(parse_localized_number("1,23,456.5"), Some(123_456.5));
+    assert_eq!(parse_localized_number("2.5 Cr views"), Some(25_000_000.0));
+    // CJK abbreviations aren't separated from the label.
+    assert_eq!(parse_localized_number("120万回視聴"), Some(1_200_000.0));
+    assert_eq!(parse_localized_number("Album"), None);
+}
+#[test]
+fn test_metric_lexicon_for_locale() {
+    assert_eq!(
+        MetricLexicon::for_locale("de"),
+        Some(MetricLexicon::new(["Aufrufe"], ["Abonnenten"]))
+    );
+    assert_eq!(MetricLexicon::for_locale("xx"), None);
+}
+#[tokio::test]
+async fn test_get_home_song_library_management() {
+    let output = parse_home_fixture("./test_json/get_home_song_menu_20261014.json").await;
+    let tokens: Vec<_> = output
+        .items()
+        .filter_map(HomeContent::as_song)
+        .map(|song| {
+            song.library_management.as_ref().map(|manager| {
+                (
+                    manager.status.clone(),
+                    manager.add_to_library_token.get_raw(),
+                    manager.remove_from_library_token.get_raw(),
+                )
+            })
+        })
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            Some((
+                LibraryStatus::NotInLibrary,
+                "AB9zfpK_mock_add_teardrop",
+                "AB9zfpK_mock_remove_teardrop"
+            )),
+            None,
+            Some((
+                LibraryStatus::NotInLibrary,
+                "AB9zfpK_mock_add_glorybox",
+                "AB9zfpK_mock_remove_glorybox"
+            )),
+        ]
+    );
+}
+#[tokio::test]
+async fn test_get_home_song_like_feedback_tokens() {
+    let output = parse_home_fixture("./test_json/get_home_song_menu_20261014.json").await;
+    let tokens: Vec<_> = output
+        .items()
+        .filter_map(HomeContent::as_song)
+        .map(|song| song.like_feedback_tokens.clone())
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            Some(LikeFeedbackTokens {
+                status: LikeStatus::Liked,
+                like_params: Some("mock_like_teardrop".to_string()),
+                dislike_params: Some("mock_dislike_teardrop".to_string()),
+                indifferent_params: Some("mock_indifferent_teardrop".to_string()),
+            }),
+            // No menu.
+            None,
+            // Menu without a like button.
+            None,
+        ]
+    );
+}
+#[tokio::test]
+async fn test_get_home_library_shelf() {
+    let output = parse_home_fixture("./test_json/get_home_library_shelf_20261014.json").await;
+    let from_library = |item: &HomeContent| match item {
+        HomeContent::Album(album) => album.from_library,
+        HomeContent::Playlist(playlist) => playlist.from_library,
+        _ => panic!("Expected only albums and playlists, got {item:?}"),
+    };
+    let flags: Vec<Vec<bool>> = output
+        .sections
+        .iter()
+        .map(|section| section.contents.iter().map(from_library).collect())
+        .collect();
+    assert_eq!(flags, [vec![true, true], vec![true], vec![false]]);
+}
+#[tokio::test]
+async fn test_home_watch_playlist_into_watch_query() {
+    let output = parse_home_fixture("./test_json/get_home_montage_20261014.json").await;
+    let mix = output.sections[0].contents[0]
+        .as_watch_playlist()
+        .expect("Expected a mix");
+    let header = GetWatchPlaylistQuery::from(mix).header();
+    assert_eq!(
+        header.get("playlistId"),
+        Some(&serde_json::json!(mix.playlist_id.get_raw()))
+    );
+    assert!(!header.contains_key("videoId"));
+}
+#[tokio::test]
+async fn test_get_home_mix_browse_endpoint() {
+    // Case where a mix card links to the playlist browse page instead of a watch
+    // playlist.
+    let output = parse_home_fixture("./test_json/get_home_mix_browse_20261014.json").await;
+    let contents = &output.sections[0].contents;
+    let mix = contents[0].as_watch_playlist().expect("Expected a mix");
+    assert_eq!(mix.title, "Discover Mix");
+    assert_eq!(mix.playlist_id.get_raw(), "RDTMAK5uy_mock_discover");
+    // Curated playlists share the radio prefix, but are still playlists.
+    assert_eq!(contents[1].kind(), HomeContentKind::Playlist);
+    let supermix = contents[2].as_watch_playlist().expect("Expected a mix");
+    assert_eq!(supermix.playlist_id.get_raw(), "RDTMAK5uy_mock_supermix");
+}
+#[tokio::test]
+async fn test_get_home_play_overlay() {
+    let output = parse_home_fixture("./test_json/get_home_play_overlay_20261014.json").await;
+    let [
+        HomeContent::Album(overlay),
+        HomeContent::Album(plain),
+        HomeContent::Playlist(playlist),
+    ] = output.sections[0].contents.as_slice()
+    else {
+        panic!(
+            "Expected two albums and a playlist, got {:#?}",
+            output.sections[0].contents
+        );
+    };
+    assert_eq!(overlay.play_params.as_deref(), Some("wAEB"));
+    assert_eq!(overlay.play_video_id, None);
+    assert_eq!(plain.play_params, None);
+    assert_eq!(plain.play_video_id, None);
+    assert_eq!(playlist.play_params.as_deref(), Some("wAEB8gECGAE%3D"));
+    assert_eq!(
+        playlist.play_video_id,
+        Some(VideoID::from_raw("u7K72X4eo_s"))
+    );
+}
+#[tokio::test]
+async fn test_get_home_audit_renderers() {
+    let source = read_fixture("./test_json/get_home_unknown_renderers_20261014.json").await;
+    let output =
+        crate::process_json::<_, BrowserToken>(source.clone(), GetHomeQuery::new()).unwrap();
+    assert_eq!(output.sections[0].contents.len(), 1);
+    assert!(output.unknown_renderers.is_empty());
+    let query = GetHomeQuery::new().with_parse_options(HomeParseOptions {
+        audit_renderers: true,
+        ..Default::default()
+    });
+    let output = crate::process_json::<_, BrowserToken>(source, query).unwrap();
+    assert_eq!(
+        output.unknown_renderers,
+        [
+            "musicMultiRowListItemRenderer",
+            "musicTastebuilderShelfRenderer"
+        ]
+    );
+}
+#[tokio::test]
+async fn test_get_home_chips() {
+    // Case where the sections can't be parsed, but the chips can.
+    let source = read_fixture("./test_json/get_home_chips_only_20261014.json").await;
+    assert!(crate::process_json::<_, BrowserToken>(source.clone(), GetHomeQuery::new()).is_err());
+    let chips = crate::process_json::<_, BrowserToken>(source, GetHomeChipsQuery).unwrap();
+    let titles: Vec<_> = chips.iter().map(|chip| chip.title.as_str()).collect();
+    assert_eq!(titles, ["Energize", "Relax", "Workout"]);
+}
+#[tokio::test]
+async fn test_get_home_more_moods_chip() {
+    let output = parse_home_fixture("./test_json/get_home_more_moods_20261014.json").await;
+    let titles: Vec<_> = output.chips.iter().map(|c| c.title.as_str()).collect();
+    assert_eq!(titles, ["Relax", "Energize"]);
+    assert_eq!(
+        output.more_moods_params,
+        Some(MoodCategoryParams::from_raw("ggMPOg1uX0FOSFdHdmdLNjFH"))
+    );
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    assert_eq!(output.more_moods_params, None);
+}
+#[tokio::test]
+async fn test_get_home_without_chips() {
+    let source = read_fixture("./test_json/get_home_20261014.json").await;
+    let output =
+        crate::process_json::<_, BrowserToken>(source.clone(), GetHomeQuery::new()).unwrap();
+    assert_eq!(output.chips.len(), 3);
+    let query = GetHomeQuery::new().with_parse_options(HomeParseOptions {
+        parse_chips: false,
+        ..Default::default()
+    });
+    let without_chips = crate::process_json::<_, BrowserToken>(source, query).unwrap();
+    assert!(without_chips.chips.is_empty());
+    assert_eq!(without_chips.sections, output.sections);
+}
+#[tokio::test]
+async fn test_get_home_thumbnail_hint_trims_thumbnails() {
+    let source = read_fixture("./test_json/get_home_20261014.json").await;
+    let output =
+        crate::process_json::<_, BrowserToken>(source.clone(), GetHomeQuery::new()).unwrap();
+    assert!(output.items().any(|item| item.thumbnails().len() > 1));
+    let query = GetHomeQuery::new().with_thumbnail_hint();
+    let trimmed = crate::process_json::<_, BrowserToken>(source, query).unwrap();
+    for (trimmed, full) in trimmed.items().zip(output.items()) {
+        let smallest = full.thumbnails().iter().min_by_key(|t| t.width);
+        assert_eq!(trimmed.thumbnails(), Vec::from_iter(smallest.cloned()));
+    }
+    // Section header thumbnails are unaffected.
+    assert_eq!(
+        trimmed
+            .sections
+            .iter()
+            .map(|s| &s.thumbnails)
+            .collect::<Vec<_>>(),
+        output
+            .sections
+            .iter()
+            .map(|s| &s.thumbnails)
+            .collect::<Vec<_>>()
+    );
+}
+#[tokio::test]
+async fn test_get_home_max_items_per_section() {
+    let source = read_fixture("./test_json/get_home_20261014.json").await;
+    let output =
+        crate::process_json::<_, BrowserToken>(source.clone(), GetHomeQuery::new()).unwrap();
+    assert_eq!(
+        output.find_section("Listen again").unwrap().contents.len(),
+        4
+    );
+    let query = GetHomeQuery::new().with_parse_options(HomeParseOptions {
+        max_items_per_section: Some(2),
+        ..Default::default()
+    });
+    let capped = crate::process_json::<_, BrowserToken>(source, query).unwrap();
+    assert_eq!(capped.sections.len(), output.sections.len());
+    for (capped, full) in capped.sections.iter().zip(&output.sections) {
+        let expected_len = full.contents.len().min(2);
+        assert_eq!(capped.contents, full.contents[..expected_len]);
+    }
+}
+#[tokio::test]
+async fn test_get_home_hero_shelf() {
+    // Case where the feed starts with an immersive shelf containing a single
+    // featured item.
+    parse_test!(
+        "./test_json/get_home_hero_20261014.json",
+        "./test_json/get_home_hero_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_explicit_status() {
+    // Case where one card has no badges at all, so its explicit status is
+    // unknown.
+    let output = parse_home_fixture("./test_json/get_home_explicit_status_20261014.json").await;
+    let explicit: Vec<_> = output
+        .items()
+        .map(|item| item.as_song().unwrap().explicit.clone())
+        .collect();
+    assert_eq!(
+        explicit,
+        [
+            Some(Explicit::IsExplicit),
+            Some(Explicit::NotExplicit),
+            None
+        ]
+    );
+    let is_explicit: Vec<_> = output.items().map(HomeContent::is_explicit).collect();
+    assert_eq!(is_explicit, [true, false, false]);
+}
+#[tokio::test]
+async fn test_get_home_explicit_video() {
+    let output = parse_home_fixture("./test_json/get_home_explicit_video_20261014.json").await;
+    let explicit: Vec<_> = output
+        .items()
+        .map(|item| item.as_video().unwrap().explicit.clone())
+        .collect();
+    assert_eq!(explicit, [Some(Explicit::IsExplicit), None]);
+    let stats = output.stats();
+    assert_eq!(stats.explicit_items, 1);
+    assert_eq!(stats.clean_items, 1);
+}
+#[tokio::test]
+async fn test_get_home_more_label() {
+    let output = parse_home_fixture("./test_json/get_home_more_label_20261014.json").await;
+    let labels: Vec<_> = output
+        .sections
+        .iter()
+        .map(|section| section.more_label.as_deref())
+        .collect();
+    assert_eq!(labels, [Some("View all 24"), None]);
+    assert_eq!(
+        output.sections[0].more_params,
+        Some(MoodCategoryParams::from_raw("ggMPOg1uX1JOQWZFeDByc2Jm"))
+    );
+}
+#[tokio::test]
+async fn test_get_home_tracking_params() {
+    let output = parse_home_fixture("./test_json/get_home_tracking_params_20261014.json").await;
+    let section = &output.sections[0];
+    assert_eq!(
+        section.tracking_params.as_deref(),
+        Some("CBMQ3BwYASITCOmock_shelf")
+    );
+    let tracking_params: Vec<_> = section
+        .contents
+        .iter()
+        .map(|item| item.as_watch_playlist().unwrap().tracking_params.as_deref())
+        .collect();
+    assert_eq!(tracking_params, [Some("CBQQoLMCGAAiEwjmock_mix"), None]);
+}
+#[tokio::test]
+async fn test_get_home_collapses_whitespace() {
+    parse_test!(
+        "./test_json/get_home_whitespace_20261014.json",
+        "./test_json/get_home_whitespace_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_multi_run_strapline() {
+    parse_test!(
+        "./test_json/get_home_strapline_runs_20261014.json",
+        "./test_json/get_home_strapline_runs_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_missing_thumbnails() {
+    parse_test!(
+        "./test_json/get_home_no_thumbnails_20261014.json",
+        "./test_json/get_home_no_thumbnails_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_album_track_count() {
+    let output = parse_home_fixture("./test_json/get_home_track_count_20261014.json").await;
+    let track_counts: Vec<_> = output
+        .items()
+        .map(|item| item.as_album().unwrap().track_count)
+        .collect();
+    assert_eq!(track_counts, [Some(11), Some(1), None]);
+}
+#[tokio::test]
+async fn test_get_home_podcast() {
+    parse_test!(
+        "./test_json/get_home_podcast_20261014.json",
+        "./test_json/get_home_podcast_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_podcast_publisher_and_episode_count() {
+    let output = parse_home_fixture("./test_json/get_home_podcast_20261014.json").await;
+    let podcasts: Vec<_> = output
+        .items()
+        .map(|item| item.as_podcast().unwrap())
+        .collect();
+    let publishers: Vec<_> = podcasts
+        .iter()
+        .map(|podcast| podcast.publisher.as_ref().map(|p| p.name.as_str()))
+        .collect();
+    assert_eq!(
+        publishers,
+        [Some("The Mock Network"), Some("99 Mock Stories"), None]
+    );
+    // A publisher name starting with a number is not an episode count.
+    let episode_counts: Vec<_> = podcasts
+        .iter()
+        .map(|podcast| podcast.episode_count)
+        .collect();
+    assert_eq!(episode_counts, [Some(120), None, Some(8)]);
+}
+#[tokio::test]
+async fn test_get_home_montage_thumbnails() {
+    // Case where a mix displays a 2x2 collage of thumbnails.
+    let output = parse_home_fixture("./test_json/get_home_montage_20261014.json").await;
+    let [HomeContent::WatchPlaylist(mix)] = output.sections[0].contents.as_slice() else {
+        panic!(
+            "Expected a single mix, got {:#?}",
+            output.sections[0].contents
+        );
+    };
+    let urls: Vec<_> = mix.thumbnails.iter().map(|t| t.url.as_str()).collect();
+    assert_eq!(
+        urls,
+        [
+            "https://lh3.googleusercontent.com/tile0=w226-h226",
+            "https://lh3.googleusercontent.com/tile1=w226-h226",
+            "https://lh3.googleusercontent.com/tile2=w226-h226",
+            "https://lh3.googleusercontent.com/tile3=w226-h226",
+        ]
+    );
+}
+#[tokio::test]
+async fn test_get_home_single_author_playlist() {
+    // Case where the only subtitle run is the author, with no type prefix.
+    parse_test!(
+        "./test_json/get_home_single_author_playlist_20261014.json",
+        "./test_json/get_home_single_author_playlist_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_quick_picks() {
+    // Quick picks shelf contains list items instead of cards.
+    parse_test!(
+        "./test_json/get_home_quick_picks_20261014.json",
+        "./test_json/get_home_quick_picks_20261014_output.txt",
+        GetHomeQuery::new(),
+        BrowserToken
+    );
+}
+#[tokio::test]
+async fn test_get_home_large_feed() {
+    // Repeat the shelves of the standard mock to simulate a very large feed.
+    let source = read_fixture("./test_json/get_home_20261014.json").await;
+    let mut json: serde_json::Value = serde_json::from_str(&source).unwrap();
+    let contents = json
+        .pointer_mut("/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents")
+        .and_then(serde_json::Value::as_array_mut)
+        .unwrap();
+    let shelves = contents.clone();
+    for _ in 0..500 {
+        contents.extend(shelves.iter().cloned());
+    }
+    // Only carousel shelves are parsed.
+    let expected_sections = contents
+        .iter()
+        .filter(|row| row.get("musicCarouselShelfRenderer").is_some())
+        .count();
+    let output =
+        crate::process_json::<_, BrowserToken>(json.to_string(), GetHomeQuery::new()).unwrap();
+    assert_eq!(output.sections.len(), expected_sections);
+    assert!(output.warnings.is_empty());
+}
+#[tokio::test]
+async fn test_get_home_malformed_rows() {
+    // Malformed shelves and items should be skipped with a warning, without
+    // failing the rest of the feed.
+    let output = parse_home_fixture("./test_json/get_home_malformed_20261014.json").await;
+    let titles: Vec<_> = output.sections.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(titles, ["Quick picks", "Listen again"]);
+    assert_eq!(output.sections[1].contents.len(), 1);
+    assert_eq!(output.warnings.len(), 2);
+    // Item errors include the kind of item and the pointer that failed.
+    let message = &output.warnings[1].message;
+    assert!(message.starts_with("Unable to parse home Song: "));
+    assert!(message.contains(
+        "/contents/2/musicCarouselShelfRenderer/contents/0/musicTwoRowItemRenderer/title/runs/0/text"
+    ));
+}
+#[test]
+fn test_error_with_context_keeps_kind() {
+    use json_crawler::JsonCrawler;
+    let json = serde_json::json!({"title": "Teardrop"});
+    let crawler = json_crawler::JsonCrawlerOwned::new(json.to_string(), json);
+    let err = crate::Error::from(crawler.navigate_pointer("/subtitle").unwrap_err())
+        .with_context("Unable to parse home Song");
+    assert_eq!(
+        err.to_string(),
+        "Unable to parse home Song: Key /subtitle not found in Api response."
+    );
+    assert!(!err.is_retryable());
+    let ErrorKind::JsonParsing(source) = err.into_kind() else {
+        panic!("Expected the navigation error to be kept");
+    };
+    assert_eq!(
+        source.to_string(),
+        "Key /subtitle not found in Api response."
+    );
+    let err = crate::Error::web("Request timed out").with_context("Unable to parse home Song");
+    assert!(err.is_retryable());
+}
+#[tokio::test]
+async fn test_get_home_selected_chip() {
+    let source = read_fixture("./test_json/get_home_mood_20261014.json").await;
+    let query =
+        GetHomeQuery::new().with_mood(MoodCategoryParams::from_raw("ggMPOg1uXzVuR0dSeVZqN0Jm"));
+    let output = crate::process_json::<_, BrowserToken>(source, query).unwrap();
+    let selected: Vec<_> = output
+        .chips
+        .iter()
+        .filter(|chip| chip.selected)
+        .map(|chip| chip.title.as_str())
+        .collect();
+    assert_eq!(output.chips.len(), 3);
+    assert_eq!(selected, ["Relax"]);
+}
+#[tokio::test]
+async fn test_get_home_active_chip() {
+    let source = read_fixture("./test_json/get_home_mood_20261014.json").await;
+    let output =
+        crate::process_json::<_, BrowserToken>(source.clone(), GetHomeQuery::new()).unwrap();
+    assert_eq!(output.active_chip, None);
+    let query =
+        GetHomeQuery::new().with_mood(MoodCategoryParams::from_raw("ggMPOg1uXzVuR0dSeVZqN0Jm"));
+    let output = crate::process_json::<_, BrowserToken>(source, query).unwrap();
+    assert_eq!(
+        output.active_chip.map(|chip| chip.title),
+        Some("Relax".to_string())
+    );
+}
+#[test]
+fn test_home_sections_try_new() {
+    let err = HomeSections::try_new(Vec::new(), Vec::new()).unwrap_err();
+    assert!(matches!(err.into_kind(), ErrorKind::EmptyResponse));
+    let home = HomeSections::try_new(Vec::new(), vec![mock_section("Listen again")]).unwrap();
+    assert_eq!(home.sections.len(), 1);
+}
+#[test]
+fn test_find_section() {
+    let home = HomeSections::from_sections(vec![
+        mock_section("Listen again"),
+        mock_section("Mixed for you"),
+    ]);
+    assert_eq!(
+        home.find_section("Mixed for you").map(|s| s.title.as_str()),
+        Some("Mixed for you")
+    );
+    assert_eq!(home.find_section("mixed for you"), None);
+    assert_eq!(
+        home.find_section_contains("MIXED")
+            .map(|s| s.title.as_str()),
+        Some("Mixed for you")
+    );
+    assert_eq!(home.find_section_contains("Quick picks"), None);
+}
+#[test]
+fn test_find_chip() {
+    let chip = |title: &str| HomeMoodChip {
+        title: title.to_string(),
+        params: MoodCategoryParams::from_raw(format!("params_{title}")),
+        selected: false,
+    };
+    let home = HomeSections::new(
+        vec![chip("Relax"), chip("Energía"), chip("Fête")],
+        Vec::new(),
+    );
+    let find = |title| home.find_chip(title).map(|chip| chip.title.as_str());
+    assert_eq!(find("energia"), Some("Energía"));
+    assert_eq!(find("ENERGÍA"), Some("Energía"));
+    assert_eq!(find("fete"), Some("Fête"));
+    // Decomposed form, 'e' followed by a combining circumflex.
+    assert_eq!(find("Fe\u{0302}te"), Some("Fête"));
+    assert_eq!(find("relax"), Some("Relax"));
+    assert_eq!(find("Energy"), None);
+}
+#[tokio::test]
+async fn test_home_section_contents_of_kind() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let listen_again = output.find_section("Listen again").unwrap();
+    let videos: Vec<_> = listen_again
+        .contents_of_kind(HomeContentKind::Video)
+        .map(|item| item.as_video().unwrap().title.as_str())
+        .collect();
+    assert_eq!(videos, ["Glory Box"]);
+    assert_eq!(
+        listen_again
+            .contents_of_kind(HomeContentKind::WatchPlaylist)
+            .count(),
+        0
+    );
+}
+#[tokio::test]
+async fn test_home_section_partition() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let kinds = |items: Vec<&HomeContent>| items.iter().map(|item| item.kind()).collect::<Vec<_>>();
+    let (playable, browsable) = output.find_section("Listen again").unwrap().partition();
+    assert_eq!(
+        kinds(playable),
+        [HomeContentKind::Song, HomeContentKind::Video]
+    );
+    assert_eq!(
+        kinds(browsable),
+        [HomeContentKind::Album, HomeContentKind::Artist]
+    );
+    let (playable, browsable) = output.find_section("Mixed for you").unwrap().partition();
+    assert_eq!(kinds(playable), [HomeContentKind::WatchPlaylist]);
+    assert_eq!(kinds(browsable), [HomeContentKind::Playlist]);
+}
+#[test]
+fn test_parse_badges() {
+    let inline = |icon: &str| {
+        serde_json::json!({ "musicInlineBadgeRenderer": {
+            "icon": { "iconType": icon },
+            "accessibilityData": { "accessibilityData": { "label": "Badge" } }
+        }})
+    };
+    let cases = [
+        (inline("MUSIC_EXPLICIT_BADGE"), Some(Badge::Explicit)),
+        (inline("MUSIC_PREMIUM"), Some(Badge::Premium)),
+        (inline("MUSIC_NEW_RELEASE"), None),
+        // Inline badge without an icon isn't recognised.
+        (
+            serde_json::json!({ "musicInlineBadgeRenderer": {
+                "accessibilityData": { "accessibilityData": { "label": "Explicit" } }
+            }}),
+            None,
+        ),
+        (
+            serde_json::json!({ "liveBadgeRenderer": {
+                "accessibility": { "accessibilityData": { "label": "LIVE" } }
+            }}),
+            None,
+        ),
+    ];
+    for (badge, expected) in cases {
+        let data = json_crawler::JsonCrawlerOwned::new(
+            String::new(),
+            serde_json::json!({ "badges": [badge] }),
+        );
+        assert_eq!(
+            parse_badges(&data, "/badges"),
+            Some(Vec::from_iter(expected))
+        );
+    }
+    let data = json_crawler::JsonCrawlerOwned::new(
+        String::new(),
+        serde_json::json!({ "badges": [inline("MUSIC_PREMIUM"), inline("MUSIC_EXPLICIT_BADGE")] }),
+    );
+    assert_eq!(
+        parse_badges(&data, "/badges"),
+        Some(vec![Badge::Premium, Badge::Explicit])
+    );
+    assert_eq!(parse_badges(&data, "/subtitleBadges"), None);
+}
+#[tokio::test]
+async fn test_home_coalesce_single_item_sections() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let items: Vec<_> = output.items().cloned().collect();
+    let single = |title: &str, item: &HomeContent| HomeSection {
+        contents: vec![item.clone()],
+        ..mock_section(title)
+    };
+    let feed = HomeSections::from_sections(vec![
+        single("Because you like Radiohead", &items[0]),
+        single("Because you like Portishead", &items[1]),
+        single("Because you like Massive Attack", &items[2]),
+        single("Live performances", &items[3]),
+        HomeSection {
+            contents: items[4..6].to_vec(),
+            ..mock_section("New releases")
+        },
+        single("Forgotten favourites", &items[6]),
+    ]);
+    let summary = |feed: &HomeSections| {
+        feed.sections
+            .iter()
+            .map(|s| (s.title.clone(), s.contents.clone()))
+            .collect::<Vec<_>>()
+    };
+    let mut by_prefix = feed.clone();
+    by_prefix.coalesce_single_item_sections(true);
+    assert_eq!(
+        summary(&by_prefix),
+        [
+            ("Because you like".to_string(), items[0..3].to_vec()),
+            ("Live performances".to_string(), items[3..4].to_vec()),
+            ("New releases".to_string(), items[4..6].to_vec()),
+            ("Forgotten favourites".to_string(), items[6..7].to_vec()),
+        ]
+    );
+    let mut all = feed.clone();
+    all.coalesce_single_item_sections(false);
+    assert_eq!(
+        summary(&all),
+        [
+            (
+                "Because you like Radiohead".to_string(),
+                items[0..4].to_vec()
+            ),
+            ("New releases".to_string(), items[4..6].to_vec()),
+            ("Forgotten favourites".to_string(), items[6..7].to_vec()),
+        ]
+    );
+    // Feeds without single item sections are unchanged.
+    let mut unchanged = output.clone();
+    unchanged.coalesce_single_item_sections(false);
+    assert_eq!(unchanged, output);
+}
+#[tokio::test]
+async fn test_home_content_kind_tags() {
+    let kinds = [
+        HomeContentKind::Song,
+        HomeContentKind::Video,
+        HomeContentKind::Album,
+        HomeContentKind::Playlist,
+        HomeContentKind::Artist,
+        HomeContentKind::WatchPlaylist,
+        HomeContentKind::Podcast,
+    ];
+    for kind in kinds {
+        assert_eq!(HomeContentKind::from_tag(kind.as_tag()), Some(kind));
+        assert_eq!(serde_json::to_value(kind).unwrap(), kind.as_tag());
+    }
+    assert_eq!(HomeContentKind::from_tag("watchPlaylist"), None);
+    // Tags match those used when serializing HomeContent.
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    for item in output.items() {
+        let json = serde_json::to_value(item).unwrap();
+        assert_eq!(json["type"], item.kind().as_tag());
+    }
+}
+#[tokio::test]
+async fn test_home_as_rows() {
+    let mut output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    output.sections.truncate(2);
+    let [listen_again, mixed] = output.sections.as_slice() else {
+        panic!("Expected two sections, got {:#?}", output.sections);
+    };
+    let mut expected = vec![HomeRow::Header {
+        title: "Listen again",
+        strapline: listen_again.strapline.as_deref(),
+    }];
+    expected.extend(listen_again.contents.iter().map(HomeRow::Item));
+    expected.push(HomeRow::Header {
+        title: "Mixed for you",
+        strapline: None,
+    });
+    expected.extend(mixed.contents.iter().map(HomeRow::Item));
+    assert!(listen_again.strapline.is_some());
+    assert_eq!(output.as_rows(), expected);
+    assert_eq!(output.as_rows().len(), 2 + output.items().count());
+}
+#[tokio::test]
+async fn test_home_artist_ids() {
+    // Radiohead and Portishead each appear on several items of the feed.
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    assert_eq!(
+        output.artist_ids(),
+        [
+            ArtistChannelID::from_raw("UCBGM-Y5b4dmjXGLqTo6Ertg"),
+            ArtistChannelID::from_raw("UCJp-y9HzDEmPpDJolDT9ynQ"),
+        ]
+    );
+}
+#[tokio::test]
+async fn test_home_listen_again() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let listen_again = output.listen_again().unwrap();
+    assert_eq!(listen_again.title, "Listen again");
+    assert_eq!(listen_again.section_kind(), HomeSectionKind::ListenAgain);
+    let without = HomeSections::from_sections(vec![mock_section("New releases")]);
+    assert_eq!(without.listen_again(), None);
+}
+#[tokio::test]
+async fn test_home_content_thumbnail_aspect() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let with_thumbnails = |kind: HomeContentKind, thumbnails: Vec<(u64, u64)>| {
+        let mut item = output
+            .items()
+            .find(|item| item.kind() == kind)
+            .unwrap()
+            .clone();
+        let thumbnails = thumbnails
+            .into_iter()
+            .map(|(width, height)| crate::common::Thumbnail {
+                width,
+                height,
+                url: format!("https://i.ytimg.com/vi/mock/{width}.jpg"),
+            })
+            .collect();
+        match &mut item {
+            HomeContent::Song(song) => song.thumbnails = thumbnails,
+            HomeContent::Video(video) => video.thumbnails = thumbnails,
+            _ => unreachable!(),
+        }
+        item
+    };
+    let song = with_thumbnails(HomeContentKind::Song, vec![(226, 226), (544, 544)]);
+    assert_eq!(song.thumbnail_aspect(), ThumbnailAspect::Square);
+    let video = with_thumbnails(HomeContentKind::Video, vec![(400, 225), (1280, 720)]);
+    assert_eq!(video.thumbnail_aspect(), ThumbnailAspect::Wide);
+    // Without thumbnails the aspect depends on the kind of item.
+    let song = with_thumbnails(HomeContentKind::Song, Vec::new());
+    assert_eq!(song.thumbnail_aspect(), ThumbnailAspect::Square);
+    let video = with_thumbnails(HomeContentKind::Video, Vec::new());
+    assert_eq!(video.thumbnail_aspect(), ThumbnailAspect::Wide);
+    // Very large dimensions must not overflow.
+    let song = with_thumbnails(HomeContentKind::Song, vec![(u64::MAX, u64::MAX), (1, 1)]);
+    assert_eq!(song.thumbnail_aspect(), ThumbnailAspect::Square);
+    let video = with_thumbnails(HomeContentKind::Video, vec![(u64::MAX, u64::MAX / 2)]);
+    assert_eq!(video.thumbnail_aspect(), ThumbnailAspect::Wide);
+}
+#[tokio::test]
+async fn test_home_content_thumbnail_is_circular() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    assert!(
+        output
+            .items()
+            .any(|item| item.kind() == HomeContentKind::Artist)
+    );
+    for item in output.items() {
+        assert_eq!(
+            item.thumbnail_is_circular(),
+            item.kind() == HomeContentKind::Artist,
+            "{item:?}"
+        );
+    }
+}
+#[tokio::test]
+async fn test_home_preview() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    assert!(
+        output
+            .sections
+            .iter()
+            .any(|section| section.contents.len() > 2)
+    );
+    let preview = output.preview(2);
+    assert_eq!(preview.chips, output.chips);
+    assert_eq!(preview.sections.len(), output.sections.len());
+    for (preview, section) in preview.sections.iter().zip(&output.sections) {
+        let expected = &section.contents[..section.contents.len().min(2)];
+        assert_eq!(preview.contents, expected);
+        assert_eq!(preview.title, section.title);
+        assert_eq!(preview.position, section.position);
+    }
+}
+#[tokio::test]
+async fn test_home_locate() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let key = |kind, id: &str| HomeContentKey {
+        kind,
+        id: id.to_string(),
+    };
+    // Glory Box is the second item of 'Listen again'.
+    assert_eq!(
+        output.locate(&key(HomeContentKind::Video, "4qQyUi4zfDs")),
+        Some((0, 1))
+    );
+    // Roads is the second item of 'New releases'.
+    assert_eq!(
+        output.locate(&key(HomeContentKind::Song, "d6XMqdl-4Xk")),
+        Some((2, 1))
+    );
+    // The kind is part of the key.
+    assert_eq!(
+        output.locate(&key(HomeContentKind::Song, "4qQyUi4zfDs")),
+        None
+    );
+    assert_eq!(
+        output.locate(&key(HomeContentKind::Video, "dQw4w9WgXcQ")),
+        None
+    );
+}
+#[tokio::test]
+async fn test_home_content_hash() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let mut resubtitled = output.clone();
+    for item in resubtitled.items_mut() {
+        if let HomeContent::Song(song) = item {
+            song.subtitle = Some("Song • 1.2M plays".to_string());
+        }
+    }
+    assert_ne!(resubtitled, output);
+    assert_eq!(resubtitled.content_hash(), output.content_hash());
+    let mut changed = output.clone();
+    let Some(HomeContent::Song(song)) = changed.items_mut().next() else {
+        panic!("Expected the first item to be a song");
+    };
+    song.video_id = VideoID::from_raw("dQw4w9WgXcQ");
+    assert_ne!(changed.content_hash(), output.content_hash());
+}
+#[tokio::test]
+async fn test_home_section_sort_contents_by_title() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let mut section = output.find_section("Listen again").unwrap().clone();
+    let retitled = |title: &str| {
+        let mut item = section.contents[0].clone();
+        if let HomeContent::Song(song) = &mut item {
+            song.title = title.to_string();
+        }
+        item
+    };
+    let extra = [retitled("Éclipse"), retitled("ok computer")];
+    section.contents.extend(extra);
+    section.sort_contents_by_title();
+    let titles = section
+        .contents
+        .iter()
+        .map(|item| item.title())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        titles,
+        [
+            "Éclipse",
+            "Glory Box",
+            "Karma Police",
+            "OK Computer",
+            "ok computer",
+            "Portishead"
+        ]
+    );
+    assert!(
+        section
+            .contents
+            .windows(2)
+            .all(|pair| pair[0].cmp_by_title(&pair[1]).is_lt())
+    );
+}
+#[tokio::test]
+async fn test_home_content_visitor() {
+    #[derive(Default)]
+    struct VideoIds(Vec<String>);
+    impl HomeContentVisitor for VideoIds {
+        fn visit_song(&mut self, song: &HomeSong) {
+            self.0.push(song.video_id.get_raw().to_string());
+        }
+        fn visit_video(&mut self, video: &HomeVideo) {
+            self.0.push(video.video_id.get_raw().to_string());
+        }
+    }
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let mut visitor = VideoIds::default();
+    output.items().for_each(|item| item.accept(&mut visitor));
+    assert_eq!(visitor.0, ["1uYWYWPc9HU", "4qQyUi4zfDs", "d6XMqdl-4Xk"]);
+}
+#[tokio::test]
+async fn test_home_mood_chip_home_query() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let chip = output.find_chip("Relax").unwrap();
+    let header = chip.home_query().header();
+    assert_eq!(
+        header.get("params"),
+        Some(&serde_json::json!(chip.params.get_raw()))
+    );
+    assert_eq!(
+        header.get("browseId"),
+        Some(&serde_json::json!("FEmusic_home"))
+    );
+}
+#[tokio::test]
+async fn test_home_content_cache_key() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let items: Vec<_> = output.items().cloned().collect();
+    // The same item may appear in several shelves, with a different subtitle.
+    let mut duplicate = items[0].clone();
+    if let HomeContent::Song(song) = &mut duplicate {
+        song.subtitle = Some("Song • Radiohead".to_string());
+    }
+    assert_ne!(duplicate, items[0]);
+    let keys: std::collections::HashSet<_> = items
+        .iter()
+        .chain(std::iter::once(&duplicate))
+        .map(HomeContent::cache_key)
+        .collect();
+    assert_eq!(keys.len(), items.len());
+    assert!(keys.contains(&HomeContentKey {
+        kind: HomeContentKind::Song,
+        id: "1uYWYWPc9HU".to_string(),
+    }));
+}
+#[tokio::test]
+async fn test_home_section_kind() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let listen_again = output.find_section("Listen again").unwrap();
+    assert_eq!(listen_again.section_kind(), HomeSectionKind::ListenAgain);
+    let new_releases = output.find_section("New releases").unwrap();
+    assert_eq!(new_releases.section_kind(), HomeSectionKind::Recommended);
+}
+#[tokio::test]
+async fn test_home_content_eq_ignoring_subtitle() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let listen_again = output.find_section("Listen again").unwrap();
+    let HomeContent::Video(video) = &listen_again.contents[1] else {
+        panic!("Expected a video, got {:#?}", listen_again.contents[1]);
+    };
+    let mut localised_video = video.clone();
+    localised_video.subtitle = Some("Portishead • 41 M de visualizaciones".to_string());
+    localised_video.views = Some("41".to_string());
+    let original = HomeContent::Video(video.clone());
+    let localised = HomeContent::Video(localised_video);
+    assert_ne!(original, localised);
+    assert!(original.eq_ignoring_subtitle(&localised));
+    assert!(!original.eq_ignoring_subtitle(&listen_again.contents[0]));
+    let mut explicit_video = video.clone();
+    explicit_video.explicit = match video.explicit {
+        Some(Explicit::IsExplicit) => Some(Explicit::NotExplicit),
+        _ => Some(Explicit::IsExplicit),
+    };
+    assert!(!original.eq_ignoring_subtitle(&HomeContent::Video(explicit_video)));
+}
+#[tokio::test]
+async fn test_home_sections_flat_round_trip() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let flat = output.to_flat();
+    assert_eq!(flat.sections[0].contents[0].kind, HomeContentKind::Song);
+    assert_eq!(flat.into_sections(), Some(output));
+}
+#[test]
+fn test_home_content_serialize_skips_none() {
+    let full = HomeContent::Song(super::HomeSong {
+        title: "Teardrop".to_string(),
+        video_id: VideoID::from_raw("u7K72X4eo_s"),
+        artists: Vec::new(),
+        album: Some(crate::parse::ParsedSongAlbum {
+            name: "Mezzanine".to_string(),
+            id: crate::common::AlbumID::from_raw("MPREb_mock_mezzanine"),
+        }),
+        explicit: Some(Explicit::NotExplicit),
+        is_premium_only: false,
+        duration: Some("5:30".to_string()),
+        duration_seconds: Some(330),
+        library_management: None,
+        like_feedback_tokens: None,
+        thumbnails: Vec::new(),
+        subtitle: Some("Massive Attack • Mezzanine".to_string()),
+        from_library: false,
+    });
+    let sparse = HomeContent::Song(super::HomeSong {
+        title: "Teardrop".to_string(),
+        video_id: VideoID::from_raw("u7K72X4eo_s"),
+        artists: Vec::new(),
+        album: None,
+        explicit: Some(Explicit::NotExplicit),
+        is_premium_only: false,
+        duration: None,
+        duration_seconds: None,
+        library_management: None,
+        like_feedback_tokens: None,
+        thumbnails: Vec::new(),
+        subtitle: None,
+        from_library: false,
+    });
+    let full_json = serde_json::to_value(&full).unwrap();
+    for key in ["album", "duration", "duration_seconds", "subtitle"] {
+        assert!(full_json.get(key).is_some(), "{key} should be serialized");
+    }
+    let sparse_json = serde_json::to_value(&sparse).unwrap();
+    assert_eq!(
+        sparse_json,
+        serde_json::json!({
+            "type": "song",
+            "title": "Teardrop",
+            "video_id": "u7K72X4eo_s",
+            "artists": [],
+            "explicit": "NotExplicit",
+            "is_premium_only": false,
+            "thumbnails": [],
+            "from_library": false,
+        })
+    );
+    for content in [full, sparse] {
+        let json = serde_json::to_string(&content).unwrap();
+        assert_eq!(serde_json::from_str::<HomeContent>(&json).unwrap(), content);
+    }
+}
+#[tokio::test]
+async fn test_home_sections_serde_round_trip() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let json = serde_json::to_string(&output).unwrap();
+    assert!(!json.contains("null"));
+    assert_eq!(serde_json::from_str::<HomeSections>(&json).unwrap(), output);
+}
+#[test]
+fn test_home_sections_deserialize_legacy() {
+    // Shape of a home feed serialized by an older version, before warnings,
+    // chip selection, durations and playlist author kinds were added.
+    let legacy = serde_json::json!({
+        "chips": [{ "title": "Relax", "params": "ggMPOg1uXzVuR0dSeVZqN0Jm" }],
+        "sections": [{
+            "title": "Quick picks",
+            "strapline": null,
+            "thumbnail": null,
+            "more_params": null,
+            "contents": [
+                {
+                    "type": "song",
+                    "title": "Teardrop",
+                    "video_id": "u7K72X4eo_s",
+                    "artists": [],
+                    "album": null,
+                    "explicit": "NotExplicit",
+                    "thumbnails": [],
+                    "subtitle": null
+                },
+                {
+                    "type": "playlist",
+                    "title": "Trip hop essentials",
+                    "playlist_id": "RDCLAK5uy_mock_triphop",
+                    "author": [],
+                    "thumbnails": [],
+                    "subtitle": null
+                }
+            ]
+        }]
+    });
+    let home: HomeSections = serde_json::from_value(legacy).unwrap();
+    assert!(home.warnings.is_empty());
+    assert!(!home.chips[0].selected);
+    assert_eq!(home.sections[0].contents.len(), 2);
+    assert_eq!(home.sections[0].shuffle_params, None);
+}
+#[tokio::test]
+async fn test_home_content_accessors() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    for item in output.items() {
+        let kind = item.kind();
+        assert_eq!(item.as_song().is_some(), kind == HomeContentKind::Song);
+        assert_eq!(item.as_video().is_some(), kind == HomeContentKind::Video);
+        assert_eq!(item.as_album().is_some(), kind == HomeContentKind::Album);
+        assert_eq!(
+            item.as_playlist().is_some(),
+            kind == HomeContentKind::Playlist
+        );
+        assert_eq!(item.as_artist().is_some(), kind == HomeContentKind::Artist);
+        assert_eq!(
+            item.as_watch_playlist().is_some(),
+            kind == HomeContentKind::WatchPlaylist
+        );
+    }
+    let song_titles: Vec<_> = output
+        .items()
+        .filter_map(HomeContent::as_song)
+        .map(|song| song.title.as_str())
+        .collect();
+    assert!(!song_titles.is_empty());
+}
+#[tokio::test]
+async fn test_home_sections_stats() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let stats = output.stats();
+    assert_eq!(stats.sections, 3);
+    assert_eq!(stats.chips, 3);
+    assert_eq!(stats.total_items, 8);
+    assert_eq!(
+        stats.items_by_kind,
+        std::collections::HashMap::from([
+            (HomeContentKind::Song, 2),
+            (HomeContentKind::Video, 1),
+            (HomeContentKind::Album, 2),
+            (HomeContentKind::Playlist, 1),
+            (HomeContentKind::Artist, 1),
+            (HomeContentKind::WatchPlaylist, 1),
+        ])
+    );
+    assert_eq!(stats.explicit_items, 2);
+    assert_eq!(stats.clean_items, 3);
+}
+#[tokio::test]
+async fn test_get_home_without_raw_subtitle() {
+    fn subtitle_mut(item: &mut HomeContent) -> &mut Option<String> {
+        match item {
+            HomeContent::Song(song) => &mut song.subtitle,
+            HomeContent::Video(video) => &mut video.subtitle,
+            HomeContent::Album(album) => &mut album.subtitle,
+            HomeContent::Playlist(playlist) => &mut playlist.subtitle,
+            HomeContent::Artist(artist) => &mut artist.subtitle,
+            HomeContent::WatchPlaylist(watch_playlist) => &mut watch_playlist.subtitle,
+            HomeContent::Podcast(podcast) => &mut podcast.subtitle,
+        }
+    }
+    let source = read_fixture("./test_json/get_home_20261014.json").await;
+    let mut full =
+        crate::process_json::<_, BrowserToken>(source.clone(), GetHomeQuery::new()).unwrap();
+    let query = GetHomeQuery::new().with_parse_options(HomeParseOptions {
+        keep_raw_subtitle: false,
+        ..Default::default()
+    });
+    let mut compact = crate::process_json::<_, BrowserToken>(source, query).unwrap();
+    assert!(full.items_mut().all(|item| subtitle_mut(item).is_some()));
+    assert!(compact.items_mut().all(|item| subtitle_mut(item).is_none()));
+    // Fields derived from the subtitle are unaffected.
+    for item in full.items_mut() {
+        *subtitle_mut(item) = None;
+    }
+    assert_eq!(compact, full);
+}
+#[tokio::test]
+async fn test_home_sections_items() {
+    let mut output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let expected: usize = output.sections.iter().map(|s| s.contents.len()).sum();
+    assert_eq!(output.items().count(), expected);
+    for item in output.items_mut() {
+        if let HomeContent::Song(song) = item {
+            song.subtitle = None;
+        }
+    }
+    assert!(output.items().all(|item| match item {
+        HomeContent::Song(song) => song.subtitle.is_none(),
+        _ => true,
+    }));
+}
+#[cfg(feature = "thumbnail-fetch")]
+#[tokio::test]
+async fn test_home_content_fetch_thumbnail() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    const FAKE_IMAGE: &[u8] = b"\x89PNG fake image bytes";
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buf = [0; 1024];
+        let n = stream.read(&mut buf).await.unwrap();
+        let request = String::from_utf8_lossy(&buf[..n]).to_string();
+        let header = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            FAKE_IMAGE.len()
+        );
+        stream.write_all(header.as_bytes()).await.unwrap();
+        stream.write_all(FAKE_IMAGE).await.unwrap();
+        request
+    });
+    let thumbnail = |width, path| crate::common::Thumbnail {
+        height: width,
+        width,
+        url: format!("http://{addr}/{path}"),
+    };
+    let content = HomeContent::WatchPlaylist(super::HomeWatchPlaylist {
+        title: "My Supermix".to_string(),
+        playlist_id: crate::common::PlaylistID::from_raw("RDTMAK5uy_mock"),
+        thumbnails: vec![
+            thumbnail(60, "small"),
+            thumbnail(226, "medium"),
+            thumbnail(544, "large"),
+        ],
+        subtitle: None,
+        tracking_params: None,
+        from_library: false,
+    });
+    let bytes = content
+        .fetch_thumbnail(&reqwest::Client::new(), 200)
+        .await
+        .unwrap();
+    assert_eq!(bytes, FAKE_IMAGE);
+    assert!(server.await.unwrap().starts_with("GET /medium "));
+}
+#[cfg(feature = "thumbnail-fetch")]
+#[tokio::test]
+async fn test_home_content_fetch_thumbnail_no_thumbnails() {
+    let content = HomeContent::WatchPlaylist(super::HomeWatchPlaylist {
+        title: "My Supermix".to_string(),
+        playlist_id: crate::common::PlaylistID::from_raw("RDTMAK5uy_mock"),
+        thumbnails: Vec::new(),
+        subtitle: None,
+        tracking_params: None,
+        from_library: false,
+    });
+    let err = content
+        .fetch_thumbnail(&reqwest::Client::new(), 200)
+        .await
+        .unwrap_err();
+    assert!(!err.is_retryable());
+    assert!(matches!(err.into_kind(), ErrorKind::NoThumbnail));
+}
+#[cfg(feature = "thumbnail-fetch")]
+#[tokio::test]
+async fn test_home_section_prefetch_thumbnails() {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    const FAKE_IMAGE: &[u8] = b"\x89PNG fake image bytes";
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+    let server = {
+        let in_flight = in_flight.clone();
+        let max_in_flight = max_in_flight.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let in_flight = in_flight.clone();
+                let max_in_flight = max_in_flight.clone();
+                tokio::spawn(async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+                    let mut buf = [0; 1024];
+                    assert_ne!(stream.read(&mut buf).await.unwrap(), 0);
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                    let header = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                        FAKE_IMAGE.len()
+                    );
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    stream.write_all(header.as_bytes()).await.unwrap();
+                    stream.write_all(FAKE_IMAGE).await.unwrap();
+                });
+            }
+        })
+    };
+    let mix = |idx: usize, has_thumbnail: bool| {
+        HomeContent::WatchPlaylist(super::HomeWatchPlaylist {
+            title: format!("Mix {idx}"),
+            playlist_id: crate::common::PlaylistID::from_raw(format!("RDTMAK5uy_mock_{idx}")),
+            thumbnails: has_thumbnail
+                .then(|| crate::common::Thumbnail {
+                    height: 226,
+                    width: 226,
+                    url: format!("http://{addr}/{idx}"),
+                })
+                .into_iter()
+                .collect(),
+            subtitle: None,
+            tracking_params: None,
+            from_library: false,
+        })
+    };
+    let section = HomeSection {
+        contents: vec![
+            mix(0, true),
+            mix(1, true),
+            mix(2, false),
+            mix(3, true),
+            mix(4, true),
+        ],
+        ..mock_section("Mixed for you")
+    };
+    let results = section
+        .prefetch_thumbnails(&reqwest::Client::new(), 200, 2)
+        .await;
+    server.abort();
+    let succeeded: Vec<_> = results.iter().map(Result::is_ok).collect();
+    assert_eq!(succeeded, [true, true, false, true, true]);
+    assert!(
+        results
+            .into_iter()
+            .flatten()
+            .all(|bytes| bytes == FAKE_IMAGE)
+    );
+    assert!(max_in_flight.load(Ordering::SeqCst) <= 2);
+}
+#[tokio::test]
+async fn test_home_sections_sort_by_kind_priority() {
+    let mut output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    output.sort_by_kind_priority(&[HomeSectionKind::Recommended, HomeSectionKind::MoodMix]);
+    let titles: Vec<_> = output.sections.iter().map(|s| s.title.as_str()).collect();
+    // 'Listen again' is not included in the order, so is placed last.
+    assert_eq!(titles, ["New releases", "Mixed for you", "Listen again"]);
+    output.sort_sections_by(|a, b| a.title.cmp(&b.title));
+    let titles: Vec<_> = output.sections.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(titles, ["Listen again", "Mixed for you", "New releases"]);
+}
+#[tokio::test]
+async fn test_home_content_browse_queries() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let browse_id = |header: serde_json::Map<String, serde_json::Value>| header["browseId"].clone();
+    for item in output.items() {
+        match item {
+            HomeContent::Album(album) => assert_eq!(
+                browse_id(album.browse_query().header()),
+                album.album_id.get_raw()
+            ),
+            HomeContent::Artist(artist) => assert_eq!(
+                browse_id(artist.browse_query().header()),
+                artist.channel_id.get_raw()
+            ),
+            HomeContent::Playlist(playlist) => assert_eq!(
+                browse_id(playlist.browse_query().header()),
+                format!("VL{}", playlist.playlist_id.get_raw())
+            ),
+            _ => (),
+        }
+    }
+}
+#[tokio::test]
+async fn test_home_song_album_query() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let songs: Vec<_> = output.items().filter_map(HomeContent::as_song).collect();
+    assert!(songs.iter().any(|song| song.album.is_some()));
+    assert!(songs.iter().any(|song| song.album.is_none()));
+    for song in songs {
+        let browse_id = song
+            .album_query()
+            .map(|query| query.header()["browseId"].clone());
+        assert_eq!(
+            browse_id,
+            song.album
+                .as_ref()
+                .map(|album| serde_json::json!(album.id.get_raw()))
+        );
+    }
+}
+#[tokio::test]
+async fn test_home_content_display() {
+    let output = parse_home_fixture("./test_json/get_home_20261014.json").await;
+    let listen_again = output.find_section("Listen again").unwrap();
+    assert_eq!(
+        listen_again.contents[0].to_string(),
+        "Song: Karma Police — Radiohead"
+    );
+    assert_eq!(
+        listen_again.contents[2].to_string(),
+        "Album: OK Computer — Radiohead (1997)"
+    );
+    assert_eq!(listen_again.contents[3].to_string(), "Artist: Portishead");
+}
+#[cfg(feature = "test-util")]
+#[test]
+fn test_home_section_builder() {
+    use super::{HomeContentBuilder, HomeSectionBuilder};
+    let section = HomeSectionBuilder::new("Quick picks")
+        .with_strapline("MOCK USER")
+        .with_content(
+            HomeContentBuilder::song("Teardrop", "u7K72X4eo_s")
+                .with_artist("Massive Attack", Some("UCmock_massive"))
+                .with_duration("5:30")
+                .build(),
+        )
+        .with_content(HomeContentBuilder::playlist("Bristol Sound", "PLmock_bristol").build())
+        .build();
+    assert_eq!(section.title, "Quick picks");
+    assert_eq!(section.strapline.as_deref(), Some("MOCK USER"));
+    let song = section.contents[0].as_song().unwrap();
+    assert_eq!(song.video_id, VideoID::from_raw("u7K72X4eo_s"));
+    assert_eq!(song.artists[0].name, "Massive Attack");
+    assert_eq!(song.duration_seconds, Some(330));
+    assert_eq!(song.explicit, None);
+    let playlist = section.contents[1].as_playlist().unwrap();
+    assert_eq!(playlist.browse_id, "VLPLmock_bristol");
+    assert!(playlist.author.is_empty());
+}
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_home_content_from_ytmusicapi_json() {
+    // Items in the shape of ytmusicapi's get_home output.
+    let song = serde_json::json!({
+        "title": "Karma Police",
+        "videoId": "1uYWYWPc9HU",
+        "artists": [{ "name": "Radiohead", "id": "UCBGM-Y5b4dmjXGLqTo6Ertg" }],
+        "album": { "name": "OK Computer", "id": "MPREb_n1AVCRHeXYW" },
+        "isExplicit": false,
+        "thumbnails": [{ "url": "https://lh3.googleusercontent.com/mock", "width": 60, "height": 60 }]
+    });
+    let HomeContent::Song(song) = HomeContent::from_ytmusicapi_json(&song).unwrap() else {
+        panic!("Expected a song");
+    };
+    assert_eq!(song.video_id, VideoID::from_raw("1uYWYWPc9HU"));
+    assert_eq!(song.artists[0].name, "Radiohead");
+    assert_eq!(song.album.unwrap().name, "OK Computer");
+    assert_eq!(song.explicit, Some(Explicit::NotExplicit));
+    assert_eq!(song.thumbnails.len(), 1);
+    let playlist = serde_json::json!({
+        "resultType": "playlist",
+        "title": "Bristol Sound",
+        "playlistId": "PLmock_bristol",
+        "author": [{ "name": "Mock Curator", "id": "UCmock_curator" }],
+        "thumbnails": []
+    });
+    let playlist = HomeContent::from_ytmusicapi_json(&playlist).unwrap();
+    let playlist = playlist.as_playlist().unwrap();
+    assert_eq!(playlist.browse_id, "VLPLmock_bristol");
+    assert_eq!(playlist.author[0].name, "Mock Curator");
+    let mix = serde_json::json!({
+        "title": "My Supermix",
+        "playlistId": "RDTMAK5uy_kset8DisdE7LSD4TNjEVvrKRTmG7a56sY",
+        "thumbnails": []
+    });
+    assert_eq!(
+        HomeContent::from_ytmusicapi_json(&mix).unwrap().kind(),
+        HomeContentKind::WatchPlaylist
+    );
+    // Missing the id required by its kind.
+    let album = serde_json::json!({ "resultType": "album", "title": "OK Computer" });
+    assert_eq!(HomeContent::from_ytmusicapi_json(&album), None);
+}
+#[test]
+fn test_cached_home_sections_is_older_than() {
+    use std::time::{Duration, SystemTime};
+    let ten_minutes_ago = SystemTime::now() - Duration::from_secs(600);
+    let cached = CachedHomeSections::new_at(HomeSections::default(), ten_minutes_ago);
+    assert!(cached.is_older_than(Duration::from_secs(300)));
+    assert!(!cached.is_older_than(Duration::from_secs(3600)));
+    assert!(
+        !CachedHomeSections::new(HomeSections::default()).is_older_than(Duration::from_secs(60))
+    );
+    let in_future = SystemTime::now() + Duration::from_secs(600);
+    let cached = CachedHomeSections::new_at(HomeSections::default(), in_future);
+    assert!(!cached.is_older_than(Duration::ZERO));
+}
@@ -0,0 +1,142 @@
+//! Conversion from the home feed items returned by the Python `ytmusicapi`
+//! library, so that data cached from it can be migrated.
+use super::{HomeContent, HomeContentFlat, HomeContentKind};
+use crate::common::{
+    AlbumID, AlbumType, ArtistChannelID, Explicit, PlaylistID, PodcastID, Thumbnail, VideoID,
+    YoutubeID,
+};
+use crate::parse::{ParsedSongAlbum, ParsedSongArtist};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YtMusicApiItem {
+    result_type: Option<String>,
+    title: String,
+    video_id: Option<String>,
+    browse_id: Option<String>,
+    playlist_id: Option<String>,
+    #[serde(default)]
+    artists: Vec<YtMusicApiRef>,
+    #[serde(default)]
+    author: Vec<YtMusicApiRef>,
+    album: Option<YtMusicApiRef>,
+    #[serde(rename = "type")]
+    album_type: Option<String>,
+    year: Option<String>,
+    is_explicit: Option<bool>,
+    duration: Option<String>,
+    duration_seconds: Option<u32>,
+    views: Option<String>,
+    subscribers: Option<String>,
+    #[serde(default)]
+    thumbnails: Vec<Thumbnail>,
+}
+
+#[derive(Deserialize)]
+struct YtMusicApiRef {
+    name: String,
+    id: Option<String>,
+}
+
+impl From<YtMusicApiRef> for ParsedSongArtist {
+    fn from(artist: YtMusicApiRef) -> Self {
+        ParsedSongArtist {
+            name: artist.name,
+            id: artist.id.map(ArtistChannelID::from_raw),
+            page_type: None,
+        }
+    }
+}
+
+impl HomeContent {
+    /// Convert a home feed item from the Python `ytmusicapi` library into a
+    /// HomeContent.
+    /// The kind of item is taken from `resultType` if present, otherwise it's
+    /// inferred from the ids the item contains.
+    /// Returns None if the item isn't in the expected shape, or is missing the
+    /// id required by its kind.
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn from_ytmusicapi_json(value: &serde_json::Value) -> Option<HomeContent> {
+        let item = YtMusicApiItem::deserialize(value).ok()?;
+        // ytmusicapi's result types match the tags used by HomeContent.
+        let kind = match item.result_type.as_deref() {
+            Some(result_type) => HomeContentKind::from_tag(result_type)?,
+            None => infer_kind(&item)?,
+        };
+        let mut content = HomeContentFlat::empty(kind, item.title);
+        content.video_id = item.video_id.map(VideoID::from_raw);
+        match kind {
+            HomeContentKind::Album => content.album_id = item.browse_id.map(AlbumID::from_raw),
+            HomeContentKind::Artist => {
+                content.channel_id = item.browse_id.map(ArtistChannelID::from_raw)
+            }
+            HomeContentKind::Podcast => {
+                content.podcast_id = item.browse_id.map(PodcastID::from_raw)
+            }
+            // ytmusicapi gives the playlist id without the 'VL' prefix.
+            HomeContentKind::Playlist => {
+                content.browse_id = item.playlist_id.as_ref().map(|id| format!("VL{id}"))
+            }
+            _ => (),
+        }
+        content.playlist_id = item.playlist_id.map(PlaylistID::from_raw);
+        content.artists = match kind {
+            HomeContentKind::Playlist => item.author,
+            _ => item.artists,
+        }
+        .into_iter()
+        .map(ParsedSongArtist::from)
+        .collect();
+        content.album = item.album.and_then(|album| {
+            Some(ParsedSongAlbum {
+                name: album.name,
+                id: AlbumID::from_raw(album.id?),
+            })
+        });
+        content.album_type = match item.album_type.as_deref() {
+            Some("Album") => Some(AlbumType::Album),
+            Some("Single") => Some(AlbumType::Single),
+            Some("EP") => Some(AlbumType::EP),
+            _ => None,
+        };
+        content.year = item.year;
+        content.explicit = item.is_explicit.map(|is_explicit| {
+            if is_explicit {
+                Explicit::IsExplicit
+            } else {
+                Explicit::NotExplicit
+            }
+        });
+        content.duration = item.duration;
+        content.duration_seconds = item.duration_seconds;
+        content.views = item.views;
+        content.subscribers = item.subscribers;
+        content.thumbnails = item.thumbnails;
+        content.into_content()
+    }
+}
+
+/// ytmusicapi's home feed doesn't include `resultType`, so the kind is inferred
+/// from the fields of the item.
+fn infer_kind(item: &YtMusicApiItem) -> Option<HomeContentKind> {
+    if item.video_id.is_some() {
+        return if item.views.is_some() {
+            Some(HomeContentKind::Video)
+        } else {
+            Some(HomeContentKind::Song)
+        };
+    }
+    match item.browse_id.as_deref() {
+        Some(id) if id.starts_with("MPRE") => return Some(HomeContentKind::Album),
+        Some(id) if id.starts_with("UC") => return Some(HomeContentKind::Artist),
+        Some(id) if id.starts_with("MPSP") => return Some(HomeContentKind::Podcast),
+        _ => (),
+    }
+    item.playlist_id.as_ref()?;
+    if item.author.is_empty() {
+        Some(HomeContentKind::WatchPlaylist)
+    } else {
+        Some(HomeContentKind::Playlist)
+    }
+}
@@ -53,6 +53,9 @@ pub use continuations::GetContinuationsQuery;
 pub mod history;
 #[doc(inline)]
 pub use history::{AddHistoryItemQuery, GetHistoryQuery, RemoveHistoryItemsQuery};
+pub mod home;
+#[doc(inline)]
+pub use home::{GetExploreQuery, GetHomeChipsQuery, GetHomeQuery};
 pub mod library;
 #[doc(inline)]
 pub use library::{
@@ -113,6 +116,11 @@ pub trait PostQuery {
     fn header(&self) -> serde_json::Map<String, serde_json::Value>;
     fn params(&self) -> Vec<(&str, Cow<'_, str>)>;
     fn path(&self) -> &str;
+    /// Additional HTTP headers to send with the request. These override any
+    /// headers of the same name provided by the AuthToken.
+    fn extra_headers(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
 }
 /// Represents a plain GET query that can be sent to Innertube.
 pub trait GetQuery {
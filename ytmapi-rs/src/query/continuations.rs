@@ -14,6 +14,18 @@ pub struct GetContinuationsQuery<'a, Q> {
 }
 
 impl<'a, Q> GetContinuationsQuery<'a, Q> {
+    /// Create a GetContinuationsQuery from previously obtained continuation
+    /// params, e.g to resume paging after persisting the params to disk.
+    /// `query` must be the query that originally returned the params.
+    pub fn new(
+        query: &'a Q,
+        continuation_params: ContinuationParams<'static>,
+    ) -> GetContinuationsQuery<'a, Q> {
+        GetContinuationsQuery {
+            query,
+            continuation_params,
+        }
+    }
     /// Create a GetContinuationsQuery with dummy continuation params - for
     /// testing purposes.
     pub fn new_mock_unchecked(query: &'a Q) -> GetContinuationsQuery<'a, Q> {
@@ -24,13 +36,24 @@ impl<'a, Q> GetContinuationsQuery<'a, Q> {
     }
 }
 impl<'a, Q> GetContinuationsQuery<'a, Q> {
+    pub(crate) fn get_query(&self) -> &'a Q {
+        self.query
+    }
+    /// Get the continuation params, so they can be persisted and used to
+    /// resume paging later with [`GetContinuationsQuery::new`].
+    pub fn get_continuation_params(&self) -> &ContinuationParams<'static> {
+        &self.continuation_params
+    }
     pub fn from_first_result<T: ParseFromContinuable<Q>>(
         res: ProcessedResult<'a, Q>,
     ) -> crate::Result<(T, Option<GetContinuationsQuery<'a, Q>>)> {
         let query = res.query;
         let (res, continuation_params) = T::parse_from_continuable(res)?;
-        let maybe_continuation_query =
-            continuation_params.map(|continuation_params| GetContinuationsQuery {
+        // Blank params would request the same page again, so treat them as the end
+        // of the continuations.
+        let maybe_continuation_query = continuation_params
+            .filter(|continuation_params| !continuation_params.is_empty())
+            .map(|continuation_params| GetContinuationsQuery {
                 continuation_params,
                 query,
             });
@@ -41,8 +64,11 @@ impl<'a, Q> GetContinuationsQuery<'a, Q> {
     ) -> crate::Result<(T, Option<GetContinuationsQuery<'b, Q>>)> {
         let query = res.query.query;
         let (res, continuation_params) = T::parse_continuation(res)?;
-        let maybe_continuation_query =
-            continuation_params.map(|continuation_params| GetContinuationsQuery {
+        // Blank params would request the same page again, so treat them as the end
+        // of the continuations.
+        let maybe_continuation_query = continuation_params
+            .filter(|continuation_params| !continuation_params.is_empty())
+            .map(|continuation_params| GetContinuationsQuery {
                 continuation_params,
                 query,
             });
@@ -100,4 +126,29 @@ where
     fn path(&self) -> &str {
         self.query.path()
     }
+    fn extra_headers(&self) -> Vec<(&str, Cow<'_, str>)> {
+        self.query.extra_headers()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetContinuationsQuery;
+    use crate::common::{ContinuationParams, YoutubeID};
+    use crate::query::{GetHomeQuery, PostQuery};
+
+    #[test]
+    fn test_continuation_params_round_trip() {
+        let home = GetHomeQuery::new();
+        let query = GetContinuationsQuery::new(
+            &home,
+            ContinuationParams::from_raw("4qmFsgIMEgpGRW11c2ljX2hvbWU%3D"),
+        );
+        let saved = serde_json::to_string(query.get_continuation_params()).unwrap();
+        let restored: ContinuationParams = serde_json::from_str(&saved).unwrap();
+        assert_eq!(restored.get_raw(), "4qmFsgIMEgpGRW11c2ljX2hvbWU%3D");
+        let resumed = GetContinuationsQuery::new(&home, restored);
+        assert_eq!(resumed.params(), query.params());
+        assert_eq!(resumed.header(), home.header());
+    }
 }
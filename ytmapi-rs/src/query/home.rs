@@ -0,0 +1,324 @@
+use super::{PostMethod, PostQuery, Query};
+use crate::auth::AuthToken;
+use crate::common::{BrandAccountID, MoodCategoryParams};
+use crate::parse::{HomeMoodChip, HomeParseOptions, HomeSections, MetricLexicon};
+use serde_json::json;
+use std::borrow::Cow;
+
+const HOME_BROWSE_ID: &str = "FEmusic_home";
+
+/// Get the YouTube Music home feed.
+/// The feed can optionally be filtered using the params from one of the mood
+/// chips returned as part of the feed.
+#[derive(PartialEq, Eq, Hash, Clone, Default)]
+pub struct GetHomeQuery<'a> {
+    params: Option<MoodCategoryParams<'a>>,
+    on_behalf_of_user: Option<BrandAccountID<'a>>,
+    browse_id: Option<String>,
+    parse_options: HomeParseOptions,
+    extra_headers: Vec<(String, String)>,
+    thumbnail_hint: bool,
+}
+
+/// Get the YouTube Music explore page, including new releases.
+/// The page is made up of the same kind of shelves as the home feed.
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct GetExploreQuery;
+
+/// Get only the mood chips from the top of the home feed, without parsing its
+/// sections.
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct GetHomeChipsQuery;
+
+impl<'a> GetHomeQuery<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Get the home feed for a brand account, instead of the signed in user's
+    /// personal account.
+    pub fn new_for_account(account: impl Into<BrandAccountID<'a>>) -> Self {
+        GetHomeQuery {
+            on_behalf_of_user: Some(account.into()),
+            ..Default::default()
+        }
+    }
+    /// Filter the home feed using the params from a mood chip.
+    pub fn with_mood(self, params: impl Into<MoodCategoryParams<'a>>) -> GetHomeQuery<'a> {
+        GetHomeQuery {
+            params: Some(params.into()),
+            ..self
+        }
+    }
+    /// Use an alternative browse id to `FEmusic_home`, for related feeds that
+    /// share the same format.
+    pub fn with_browse_id(mut self, id: impl Into<String>) -> GetHomeQuery<'a> {
+        self.browse_id = Some(id.into());
+        self
+    }
+    /// Use a custom set of words to identify view and subscriber counts, for
+    /// locales that aren't built-in.
+    pub fn with_lexicon(mut self, lexicon: MetricLexicon) -> GetHomeQuery<'a> {
+        self.parse_options.lexicon = lexicon;
+        self
+    }
+    /// Set the options used when parsing the feed.
+    pub fn with_parse_options(mut self, options: HomeParseOptions) -> GetHomeQuery<'a> {
+        self.parse_options = options;
+        self
+    }
+    /// Hint to the server that the client prefers smaller images, to reduce the
+    /// cost of parsing and storing the feed on low-end devices.
+    /// As the server doesn't always honour the hint, each item is also trimmed
+    /// to its smallest thumbnail when parsed - see
+    /// [`HomeParseOptions::smallest_thumbnail_only`].
+    pub fn with_thumbnail_hint(mut self) -> GetHomeQuery<'a> {
+        self.thumbnail_hint = true;
+        self.parse_options.smallest_thumbnail_only = true;
+        self
+    }
+    /// Send an additional HTTP header with the request, e.g a custom
+    /// `X-Goog-Visitor-Id`. This overrides any header of the same name set by
+    /// the AuthToken.
+    pub fn with_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> GetHomeQuery<'a> {
+        self.extra_headers.push((name.into(), value.into()));
+        self
+    }
+    pub(crate) fn get_parse_options(&self) -> &HomeParseOptions {
+        &self.parse_options
+    }
+    pub(crate) fn get_mood_params(&self) -> Option<&MoodCategoryParams<'a>> {
+        self.params.as_ref()
+    }
+}
+
+impl<A: AuthToken> Query<A> for GetHomeQuery<'_> {
+    type Output = HomeSections;
+    type Method = PostMethod;
+}
+impl PostQuery for GetHomeQuery<'_> {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        let browse_id = self.browse_id.as_deref().unwrap_or(HOME_BROWSE_ID);
+        let mut header = serde_json::Map::from_iter([("browseId".to_string(), json!(browse_id))]);
+        if let Some(params) = &self.params {
+            header.insert("params".to_string(), json!(params));
+        }
+        // Merged into the default request context.
+        let mut context = serde_json::Map::new();
+        if let Some(account) = &self.on_behalf_of_user {
+            context.insert("user".to_string(), json!({ "onBehalfOfUser": account }));
+        }
+        if self.thumbnail_hint {
+            context.insert(
+                "client".to_string(),
+                json!({ "screenDensityFloat": 1, "screenPixelDensity": 1 }),
+            );
+        }
+        if !context.is_empty() {
+            header.insert("context".to_string(), context.into());
+        }
+        header
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "browse"
+    }
+    fn extra_headers(&self) -> Vec<(&str, Cow<'_, str>)> {
+        self.extra_headers
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str().into()))
+            .collect()
+    }
+}
+
+impl<A: AuthToken> Query<A> for GetHomeChipsQuery {
+    type Output = Vec<HomeMoodChip>;
+    type Method = PostMethod;
+}
+impl PostQuery for GetHomeChipsQuery {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::Map::from_iter([("browseId".to_string(), json!(HOME_BROWSE_ID))])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "browse"
+    }
+}
+
+impl<A: AuthToken> Query<A> for GetExploreQuery {
+    type Output = HomeSections;
+    type Method = PostMethod;
+}
+impl PostQuery for GetExploreQuery {
+    fn header(&self) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::Map::from_iter([("browseId".to_string(), json!("FEmusic_explore"))])
+    }
+    fn params(&self) -> Vec<(&str, Cow<'_, str>)> {
+        vec![]
+    }
+    fn path(&self) -> &str {
+        "browse"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GetHomeQuery;
+    use crate::auth::{BrowserToken, merge_headers};
+    use crate::common::{BrandAccountID, MoodCategoryParams, YoutubeID};
+    use crate::query::PostQuery;
+    use crate::utils::{MockRequest, serve_mock_responses};
+    use serde_json::json;
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    #[test]
+    fn test_get_home_query_for_account() {
+        let header = GetHomeQuery::new().header();
+        assert!(!header.contains_key("context"));
+        let header =
+            GetHomeQuery::new_for_account(BrandAccountID::from_raw("110961024522727593836"))
+                .header();
+        assert_eq!(
+            header.get("context"),
+            Some(&json!({ "user": { "onBehalfOfUser": "110961024522727593836" } }))
+        );
+        assert_eq!(header.get("browseId"), Some(&json!("FEmusic_home")));
+    }
+    #[test]
+    fn test_get_home_query_with_thumbnail_hint() {
+        let header =
+            GetHomeQuery::new_for_account(BrandAccountID::from_raw("110961024522727593836"))
+                .with_thumbnail_hint()
+                .header();
+        assert_eq!(
+            header.get("context"),
+            Some(&json!({
+                "user": { "onBehalfOfUser": "110961024522727593836" },
+                "client": { "screenDensityFloat": 1, "screenPixelDensity": 1 }
+            }))
+        );
+        let query = GetHomeQuery::new().with_thumbnail_hint();
+        assert!(query.get_parse_options().smallest_thumbnail_only);
+    }
+    #[test]
+    fn test_get_home_query_with_browse_id() {
+        let header = GetHomeQuery::new()
+            .with_browse_id("FEmusic_home_kids")
+            .header();
+        assert_eq!(header.get("browseId"), Some(&json!("FEmusic_home_kids")));
+    }
+    #[tokio::test]
+    async fn test_get_home_end_to_end() {
+        // Canned browse response, served by a local mock of the API.
+        let response = tokio::fs::read_to_string("./test_json/get_home_20261014.json")
+            .await
+            .expect("Expect file read to pass during tests");
+        let (addr, server) = serve_mock_responses(vec![Some(response.clone())]).await;
+        let yt = crate::YtMusic {
+            client: crate::Client::new()
+                .unwrap()
+                .with_api_url(format!("http://{addr}/youtubei/v1/")),
+            token: BrowserToken::new_mock(),
+        };
+        let query = GetHomeQuery::new().with_header("X-Goog-Visitor-Id", "CgtNb2NrVmlzaXRvcg");
+        let output = yt.query(query.clone()).await.unwrap();
+        let expected = crate::process_json::<_, BrowserToken>(response, query.clone()).unwrap();
+        assert_eq!(output, expected);
+        assert!(!output.chips.is_empty());
+        let [MockRequest { head, body }] = server.await.unwrap().try_into().unwrap();
+        assert!(head[0].starts_with("POST /youtubei/v1/browse?alt=json"));
+        assert!(
+            head.iter()
+                .any(|h| h.eq_ignore_ascii_case("x-goog-visitor-id: CgtNb2NrVmlzaXRvcg"))
+        );
+        assert_eq!(body["browseId"], json!("FEmusic_home"));
+        assert_eq!(body["context"]["client"]["clientName"], json!("WEB_REMIX"));
+    }
+    #[tokio::test]
+    async fn test_home_snapshots_with_cancel() {
+        use futures::StreamExt;
+        use tokio_util::sync::CancellationToken;
+        // The first page has a continuation, but the mock only serves one
+        // response, so a second request would return an error.
+        let response = tokio::fs::read_to_string("./test_json/get_home_20261014.json")
+            .await
+            .expect("Expect file read to pass during tests");
+        let (addr, server) = serve_mock_responses(vec![Some(response)]).await;
+        let yt = crate::YtMusic {
+            client: crate::Client::new()
+                .unwrap()
+                .with_api_url(format!("http://{addr}/youtubei/v1/")),
+            token: BrowserToken::new_mock(),
+        };
+        let query = GetHomeQuery::new();
+        let cancel = CancellationToken::new();
+        let mut snapshots = std::pin::pin!(yt.home_snapshots_with_cancel(&query, cancel.clone()));
+        let first = snapshots.next().await.unwrap().unwrap();
+        assert!(!first.sections.is_empty());
+        cancel.cancel();
+        assert!(snapshots.next().await.is_none());
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 1);
+    }
+    #[cfg(feature = "simplified-queries")]
+    #[tokio::test]
+    async fn test_get_listen_again_end_to_end() {
+        let response = tokio::fs::read_to_string("./test_json/get_home_20261014.json")
+            .await
+            .expect("Expect file read to pass during tests");
+        let (addr, server) = serve_mock_responses(vec![Some(response)]).await;
+        let yt = crate::YtMusic {
+            client: crate::Client::new()
+                .unwrap()
+                .with_api_url(format!("http://{addr}/youtubei/v1/")),
+            token: BrowserToken::new_mock(),
+        };
+        let section = yt.get_listen_again().await.unwrap().unwrap();
+        assert_eq!(section.title, "Listen again");
+        assert!(!section.contents.is_empty());
+        let [MockRequest { body, .. }] = server.await.unwrap().try_into().unwrap();
+        assert_eq!(body["browseId"], json!("FEmusic_home"));
+    }
+    #[test]
+    fn test_get_home_query_eq_hash() {
+        let hash = |query: &GetHomeQuery| {
+            let mut hasher = DefaultHasher::new();
+            query.hash(&mut hasher);
+            hasher.finish()
+        };
+        let build = || {
+            GetHomeQuery::new_for_account(BrandAccountID::from_raw("110961024522727593836"))
+                .with_mood(MoodCategoryParams::from_raw("ggMPOg1uX1JOQWZFeDByc2Jm"))
+        };
+        assert!(build() == build());
+        assert_eq!(hash(&build()), hash(&build()));
+        let other = build().with_browse_id("FEmusic_home_kids");
+        assert!(build() != other);
+    }
+    #[test]
+    fn test_get_home_query_extra_headers() {
+        let query = GetHomeQuery::new()
+            .with_header("X-Goog-Visitor-Id", "CgtNb2NrVmlzaXRvcg%3D%3D")
+            .with_header("accept", "application/json");
+        let auth_headers = [
+            ("Content-Type", "application/json".into()),
+            ("Accept", "*/*".into()),
+        ];
+        let headers = merge_headers(auth_headers, query.extra_headers());
+        assert_eq!(
+            headers,
+            [
+                ("Content-Type", "application/json".into()),
+                ("X-Goog-Visitor-Id", "CgtNb2NrVmlzaXRvcg%3D%3D".into()),
+                ("accept", "application/json".into()),
+            ]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use super::{PostMethod, PostQuery, Query};
 use crate::auth::{AuthToken, LoggedIn};
 use crate::common::{PlaylistID, SetVideoID, VideoID, YoutubeID};
-use crate::parse::{GetPlaylistDetails, PlaylistItem};
+use crate::parse::{GetPlaylistDetails, HomeWatchPlaylist, PlaylistItem};
 pub use additems::*;
 pub use create::*;
 pub use edit::*;
@@ -163,6 +163,15 @@ impl<'a> GetWatchPlaylistQuery<PlaylistID<'a>> {
     }
 }
 
+/// Start playback of a mix from the home feed.
+/// Note that mixes may also require params for correct ordering, which are not
+/// currently returned as part of the home feed.
+impl<'a> From<&'a HomeWatchPlaylist> for GetWatchPlaylistQuery<PlaylistID<'a>> {
+    fn from(value: &'a HomeWatchPlaylist) -> Self {
+        GetWatchPlaylistQuery::new_from_playlist_id((&value.playlist_id).into())
+    }
+}
+
 impl<A: AuthToken> Query<A> for GetPlaylistTracksQuery<'_> {
     type Output = Vec<PlaylistItem>;
     type Method = PostMethod;
@@ -14,10 +14,11 @@ use crate::common::{
 };
 use crate::parse::{
     AddPlaylistItem, GetAlbum, GetArtist, GetArtistAlbumsAlbum, GetPlaylistDetails, GetUser,
-    HistoryPeriod, LibraryArtist, LibraryArtistSubscription, LibraryPlaylist, Lyrics, PlaylistItem,
-    SearchResultAlbum, SearchResultArtist, SearchResultEpisode, SearchResultFeaturedPlaylist,
-    SearchResultPlaylist, SearchResultPodcast, SearchResultProfile, SearchResultSong,
-    SearchResultVideo, SearchResults, UserPlaylist, UserVideo, WatchPlaylistTrack,
+    HistoryPeriod, HomeSection, LibraryArtist, LibraryArtistSubscription, LibraryPlaylist, Lyrics,
+    PlaylistItem, SearchResultAlbum, SearchResultArtist, SearchResultEpisode,
+    SearchResultFeaturedPlaylist, SearchResultPlaylist, SearchResultPodcast, SearchResultProfile,
+    SearchResultSong, SearchResultVideo, SearchResults, UserPlaylist, UserVideo,
+    WatchPlaylistTrack,
 };
 use crate::query::playlist::{CreatePlaylistType, DuplicateHandlingMode, GetPlaylistDetailsQuery};
 use crate::query::rate::{RatePlaylistQuery, RateSongQuery};
@@ -31,15 +32,16 @@ use crate::query::{
     AddHistoryItemQuery, AddPlaylistItemsQuery, CreatePlaylistQuery, DeletePlaylistQuery,
     DeleteUploadEntityQuery, EditPlaylistQuery, EditSongLibraryStatusQuery, GetAlbumQuery,
     GetArtistAlbumsQuery, GetArtistQuery, GetChannelEpisodesQuery, GetChannelQuery,
-    GetEpisodeQuery, GetHistoryQuery, GetLibraryAlbumsQuery, GetLibraryArtistSubscriptionsQuery,
-    GetLibraryArtistsQuery, GetLibraryChannelsQuery, GetLibraryPlaylistsQuery,
-    GetLibraryPodcastsQuery, GetLibrarySongsQuery, GetLibraryUploadAlbumQuery,
-    GetLibraryUploadAlbumsQuery, GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery,
-    GetLibraryUploadSongsQuery, GetLyricsIDQuery, GetMoodCategoriesQuery, GetMoodPlaylistsQuery,
-    GetNewEpisodesQuery, GetPlaylistTracksQuery, GetPodcastQuery, GetSearchSuggestionsQuery,
-    GetTasteProfileQuery, GetUserPlaylistsQuery, GetUserQuery, GetUserVideosQuery,
-    GetWatchPlaylistQuery, Query, RemoveHistoryItemsQuery, RemovePlaylistItemsQuery, SearchQuery,
-    SetTasteProfileQuery, SubscribeArtistQuery, UnsubscribeArtistsQuery,
+    GetEpisodeQuery, GetExploreQuery, GetHistoryQuery, GetHomeChipsQuery, GetHomeQuery,
+    GetLibraryAlbumsQuery, GetLibraryArtistSubscriptionsQuery, GetLibraryArtistsQuery,
+    GetLibraryChannelsQuery, GetLibraryPlaylistsQuery, GetLibraryPodcastsQuery,
+    GetLibrarySongsQuery, GetLibraryUploadAlbumQuery, GetLibraryUploadAlbumsQuery,
+    GetLibraryUploadArtistQuery, GetLibraryUploadArtistsQuery, GetLibraryUploadSongsQuery,
+    GetLyricsIDQuery, GetMoodCategoriesQuery, GetMoodPlaylistsQuery, GetNewEpisodesQuery,
+    GetPlaylistTracksQuery, GetPodcastQuery, GetSearchSuggestionsQuery, GetTasteProfileQuery,
+    GetUserPlaylistsQuery, GetUserQuery, GetUserVideosQuery, GetWatchPlaylistQuery, Query,
+    RemoveHistoryItemsQuery, RemovePlaylistItemsQuery, SearchQuery, SetTasteProfileQuery,
+    SubscribeArtistQuery, UnsubscribeArtistsQuery,
 };
 use crate::{Result, YtMusic};
 
@@ -380,6 +382,46 @@ impl<A: AuthToken> YtMusic<A> {
         self.query(GetMoodPlaylistsQuery::new(mood_params.into()))
             .await
     }
+    /// Fetches the home feed.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE").await.unwrap();
+    /// yt.get_home().await
+    /// # };
+    pub async fn get_home(&self) -> Result<<GetHomeQuery<'_> as Query<A>>::Output> {
+        self.query(GetHomeQuery::new()).await
+    }
+    /// Fetches only the 'Listen again' shelf of the home feed, made up of items
+    /// the user has played recently. Returns None if the feed doesn't have
+    /// one. See [`crate::parse::HomeSections::listen_again`].
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE").await.unwrap();
+    /// yt.get_listen_again().await
+    /// # };
+    pub async fn get_listen_again(&self) -> Result<Option<HomeSection>> {
+        let home = self.get_home().await?;
+        Ok(home.listen_again().cloned())
+    }
+    /// Fetches only the mood chips from the top of the home feed. Useful to
+    /// show the chips before the rest of the feed has loaded.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE").await.unwrap();
+    /// yt.get_home_chips().await
+    /// # };
+    pub async fn get_home_chips(&self) -> Result<<GetHomeChipsQuery as Query<A>>::Output> {
+        self.query(GetHomeChipsQuery).await
+    }
+    /// Fetches the explore page, including new releases.
+    /// ```no_run
+    /// # async {
+    /// let yt = ytmapi_rs::YtMusic::from_cookie("FAKE COOKIE").await.unwrap();
+    /// yt.get_explore().await
+    /// # };
+    pub async fn get_explore(&self) -> Result<<GetExploreQuery as Query<A>>::Output> {
+        self.query(GetExploreQuery).await
+    }
     /// Get the 'SongTrackingUrl' for a song. This is used to add items to
     /// history using `add_history_item()`.
     /// ```no_run
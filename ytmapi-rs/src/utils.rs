@@ -164,3 +164,64 @@ macro_rules! parse_with_matching_continuation_test {
         pretty_assertions::assert_eq!(expected, output_continuation);
     };
 }
+
+/// A request received by [`serve_mock_responses`].
+#[cfg(test)]
+#[derive(Debug)]
+pub(crate) struct MockRequest {
+    /// Request line and headers, one per line.
+    pub head: Vec<String>,
+    pub body: serde_json::Value,
+}
+
+/// Serve each of `responses` as a JSON body over plain HTTP on localhost, one
+/// per connection, returning the address and a handle to the requests
+/// received. A response of None closes the connection without replying, to
+/// simulate a transport error.
+#[cfg(test)]
+pub(crate) async fn serve_mock_responses(
+    responses: Vec<Option<String>>,
+) -> (
+    std::net::SocketAddr,
+    tokio::task::JoinHandle<Vec<MockRequest>>,
+) {
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = tokio::spawn(async move {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let mut head = Vec::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                stream.read_line(&mut line).await.unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    content_length = value.trim().parse().unwrap();
+                }
+                head.push(line.trim_end().to_string());
+            }
+            let mut body = vec![0; content_length];
+            stream.read_exact(&mut body).await.unwrap();
+            if let Some(response) = response {
+                let header = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    response.len()
+                );
+                stream.write_all(header.as_bytes()).await.unwrap();
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+            let body = serde_json::from_slice(&body).unwrap();
+            requests.push(MockRequest { head, body });
+        }
+        requests
+    });
+    (addr, server)
+}
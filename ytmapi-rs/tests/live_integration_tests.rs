@@ -126,6 +126,7 @@ generate_stream_test_logged_in!(
     test_stream_get_library_upload_artists,
     GetLibraryUploadArtistsQuery::default()
 );
+generate_stream_test!(test_stream_get_home, GetHomeQuery::new());
 generate_stream_test!(
     test_stream_search_artists,
     SearchQuery::new("Beatles").with_filter(ArtistsFilter)
@@ -182,6 +183,9 @@ generate_query_test!(
 );
 
 generate_query_test!(test_get_mood_categories, GetMoodCategoriesQuery);
+generate_query_test!(test_get_home, GetHomeQuery::new());
+generate_query_test!(test_get_explore, GetExploreQuery);
+generate_query_test!(test_get_home_chips, GetHomeChipsQuery);
 // NOTE: Set Taste Profile test is not implemented, to avoid impact to my YTM
 // recommendations.
 generate_query_test!(test_get_taste_profile, GetTasteProfileQuery);